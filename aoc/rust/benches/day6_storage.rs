@@ -0,0 +1,70 @@
+//! Compares the day 6 solvers with sparse and dense sets on the real input.
+//!
+//! Run with `cargo bench --package aoc --bench day6_storage`. Like the other benchmark, it
+//! takes days as arguments, and does nothing unless day 6 is among them.
+
+use std::time::{Duration, Instant};
+
+use aoc::{registry, stats::Summary};
+use aoc2024_common::{
+    guard::{Board, Dense, Sparse},
+    solution::Solution,
+};
+use day6_rust::{naive, Day6};
+
+/// How long to keep collecting samples once the minimum is reached.
+const MEASUREMENT: Duration = Duration::from_secs(3);
+const MIN_SAMPLES: usize = 3;
+const MAX_SAMPLES: usize = 100;
+
+type Solver = fn(&Board) -> usize;
+
+fn bench(solve: Solver, board: &Board) -> (usize, Summary) {
+    let mut samples = vec![];
+    let mut answer = 0;
+    let start = Instant::now();
+    while samples.len() < MIN_SAMPLES
+        || (samples.len() < MAX_SAMPLES && start.elapsed() < MEASUREMENT)
+    {
+        let solve_start = Instant::now();
+        answer = solve(board);
+        samples.push(solve_start.elapsed());
+    }
+    (answer, Summary::new(&samples))
+}
+
+fn main() -> anyhow::Result<()> {
+    // Cargo passes `--bench`, any other arguments select days.
+    let days: Vec<u32> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| arg.parse())
+        .collect::<Result<_, _>>()?;
+    if !days.is_empty() && !days.contains(&6) {
+        return Ok(());
+    }
+
+    let entry = registry::find(6, 1).expect("day 6 is registered");
+    let board = Day6::parse(&std::fs::read(entry.input_path())?)?;
+
+    let solvers: [(&str, &str, Solver); 6] = [
+        ("part 1", "sparse", day6_rust::part1::<Sparse>),
+        ("part 1", "dense", day6_rust::part1::<Dense>),
+        ("part 2", "sparse", day6_rust::part2::<Sparse>),
+        ("part 2", "dense", day6_rust::part2::<Dense>),
+        ("naive part 2", "sparse", naive::part2::<Sparse>),
+        ("naive part 2", "dense", naive::part2::<Dense>),
+    ];
+    println!(
+        "{:>12} {:>7} {:>7} {:>8} {:>24} {:>12}",
+        "solver", "storage", "samples", "answer", "solve (mean ± sd)", "solve median"
+    );
+    for (name, storage, solve) in solvers {
+        let (answer, summary) = bench(solve, &board);
+        println!(
+            "{name:>12} {storage:>7} {:>7} {answer:>8} {:>11.2?} ± {:>10.2?} {:>12.2?}",
+            summary.samples, summary.mean, summary.std_dev, summary.median,
+        );
+    }
+    Ok(())
+}
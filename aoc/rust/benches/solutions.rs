@@ -0,0 +1,97 @@
+//! Times the parser and solver of every part on its real input.
+//!
+//! Run with `cargo bench --package aoc`, optionally followed by `-- <day>...`
+//! to only benchmark some of the days.
+
+use std::time::{Duration, Instant};
+
+use aoc::{
+    registry::{self, Entry},
+    stats::Summary,
+};
+
+/// How long to run a part before measuring it.
+const WARM_UP: Duration = Duration::from_secs(1);
+/// How long to keep collecting samples once the minimum is reached.
+const MEASUREMENT: Duration = Duration::from_secs(3);
+const MIN_SAMPLES: usize = 3;
+const MAX_SAMPLES: usize = 100;
+
+struct Report {
+    day: u32,
+    part: u32,
+    parse: Summary,
+    solve: Summary,
+}
+
+fn bench(entry: &Entry) -> anyhow::Result<Report> {
+    let input = std::fs::read(entry.input_path())?;
+
+    let start = Instant::now();
+    while start.elapsed() < WARM_UP {
+        (entry.run)(&input)?;
+    }
+
+    let mut parse = vec![];
+    let mut solve = vec![];
+    let start = Instant::now();
+    while parse.len() < MIN_SAMPLES || (parse.len() < MAX_SAMPLES && start.elapsed() < MEASUREMENT)
+    {
+        let timed = (entry.run)(&input)?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+    }
+
+    Ok(Report {
+        day: entry.day,
+        part: entry.part,
+        parse: Summary::new(&parse),
+        solve: Summary::new(&solve),
+    })
+}
+
+fn print_table(reports: &[Report]) {
+    println!(
+        "{:>3} {:>4} {:>7} {:>24} {:>24} {:>12}",
+        "day", "part", "samples", "parse (mean ± sd)", "solve (mean ± sd)", "solve median"
+    );
+    for report in reports {
+        println!(
+            "{:>3} {:>4} {:>7} {:>11.2?} ± {:>10.2?} {:>11.2?} ± {:>10.2?} {:>12.2?}",
+            report.day,
+            report.part,
+            report.solve.samples,
+            report.parse.mean,
+            report.parse.std_dev,
+            report.solve.mean,
+            report.solve.std_dev,
+            report.solve.median,
+        );
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    // Cargo passes `--bench`, any other arguments select days.
+    let days: Vec<u32> = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| arg.parse())
+        .collect::<Result<_, _>>()?;
+
+    let mut reports = vec![];
+    for entry in registry::ENTRIES {
+        if !days.is_empty() && !days.contains(&entry.day) {
+            continue;
+        }
+        let report = bench(entry)?;
+        eprintln!(
+            "day {} part {}: parse {:.2?}, solve {:.2?} ({} samples)",
+            report.day, report.part, report.parse.mean, report.solve.mean, report.solve.samples
+        );
+        reports.push(report);
+    }
+
+    println!();
+    print_table(&reports);
+    Ok(())
+}
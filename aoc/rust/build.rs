@@ -0,0 +1,111 @@
+//! Generates one test per recorded answer in `answers.toml`, and one per example input in
+//! `day*/examples/`.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Answers {
+    answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+struct Answer {
+    day: u32,
+    part: u32,
+    answer: String,
+}
+
+#[derive(Deserialize)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// `(day, examples directory)` for every day that has one.
+fn example_dirs() -> Vec<(u32, PathBuf)> {
+    let mut dirs: Vec<_> = std::fs::read_dir("../..")
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().ok()?;
+            let day = name.strip_prefix("day")?.parse().ok()?;
+            let dir = Path::new("../..").join(name).join("examples");
+            dir.is_dir().then_some((day, dir))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn example_tests() -> String {
+    // New days are added to the workspace manifest.
+    println!("cargo:rerun-if-changed=../../Cargo.toml");
+
+    let mut tests = String::new();
+    for (day, dir) in example_dirs() {
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let expected_path = dir.join("expected.toml");
+        let expected: BTreeMap<String, Expected> = match std::fs::read_to_string(&expected_path) {
+            Ok(text) => toml::from_str(&text)
+                .unwrap_or_else(|err| panic!("failed to parse {expected_path:?}: {err}")),
+            Err(_) => BTreeMap::new(),
+        };
+
+        // A day fresh from `aoc new` has no answers yet, and nothing to check.
+        if expected.is_empty() {
+            continue;
+        }
+
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.ends_with(".txt"))
+            .collect();
+        files.sort();
+
+        for file in files {
+            let stem = file.trim_end_matches(".txt");
+            let name: String = stem
+                .chars()
+                .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+                .collect();
+            let (part1, part2) = expected
+                .get(&file)
+                .map_or((None, None), |e| (e.part1.clone(), e.part2.clone()));
+            writeln!(
+                tests,
+                "#[test]\nfn day{day}_{name}() {{\n    check({day}, {file:?}, {part1:?}, {part2:?});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+    tests
+}
+
+fn main() {
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("examples.rs"), example_tests()).unwrap();
+
+    let path = "../../answers.toml";
+    println!("cargo:rerun-if-changed={path}");
+
+    let text = std::fs::read_to_string(path).expect("failed to read answers.toml");
+    let answers: Answers = toml::from_str(&text).expect("failed to parse answers.toml");
+
+    let mut tests = String::new();
+    for Answer { day, part, answer } in answers.answer {
+        writeln!(
+            tests,
+            "#[test]\nfn day{day}_part{part}() {{\n    check({day}, {part}, {answer:?});\n}}\n"
+        )
+        .unwrap();
+    }
+
+    std::fs::write(Path::new(&out_dir).join("answers.rs"), tests).unwrap();
+}
@@ -0,0 +1,37 @@
+//! The answers recorded in `answers.toml` for every part's real input.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::workspace_dir;
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+struct Answer {
+    day: u32,
+    part: u32,
+    answer: String,
+}
+
+pub fn path() -> PathBuf {
+    workspace_dir().join("answers.toml")
+}
+
+/// Recorded answers by day and part.
+pub fn read() -> anyhow::Result<BTreeMap<(u32, u32), String>> {
+    let path = path();
+    let text =
+        std::fs::read_to_string(&path).with_context(|| format!("failed to read {path:?}"))?;
+    let file: AnswersFile = toml::from_str(&text).with_context(|| format!("invalid {path:?}"))?;
+    Ok(file
+        .answer
+        .into_iter()
+        .map(|Answer { day, part, answer }| ((day, part), answer))
+        .collect())
+}
@@ -0,0 +1,71 @@
+//! HTTP client for adventofcode.com.
+
+use anyhow::Context;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2024;
+/// Identifies the tool to the site's maintainers, as they ask automated requests to do.
+pub const USER_AGENT: &str = "github.com/Kinrany/aoc2024 aoc-runner";
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    fn url(&self, day: u32, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn input(&self, day: u32) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                anyhow::bail!(
+                    "failed to download day {day} input: {status} {}",
+                    body.trim()
+                )
+            }
+            Err(err) => Err(err).context("failed to download input"),
+        }
+    }
+
+    /// Posts an answer and returns the HTML page with the site's verdict.
+    pub fn answer(&self, day: u32, part: u32, answer: &str) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                anyhow::bail!(
+                    "failed to submit day {day} part {part}: {status} {}",
+                    body.trim()
+                )
+            }
+            Err(err) => Err(err).context("failed to submit answer"),
+        }
+    }
+}
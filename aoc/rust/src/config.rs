@@ -0,0 +1,50 @@
+//! Local settings and caches, kept out of git under `.aoc/`.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::workspace_dir;
+
+/// Environment variable with the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Deserialize)]
+struct Config {
+    session: Option<String>,
+}
+
+pub fn aoc_dir() -> PathBuf {
+    workspace_dir().join(".aoc")
+}
+
+/// Where downloaded puzzle inputs are kept.
+pub fn cache_dir() -> PathBuf {
+    aoc_dir().join("cache")
+}
+
+/// Every answer submitted by `aoc submit` and the site's verdict.
+pub fn submissions_path() -> PathBuf {
+    aoc_dir().join("submissions.toml")
+}
+
+/// Holds `session = "..."` when the environment variable is not set.
+pub fn config_path() -> PathBuf {
+    aoc_dir().join("config.toml")
+}
+
+/// Session cookie from `$AOC_SESSION`, or else from `.aoc/config.toml`.
+pub fn session() -> anyhow::Result<String> {
+    if let Ok(session) = std::env::var(SESSION_VAR) {
+        return Ok(session.trim().into());
+    }
+    let path = config_path();
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("no session: set ${SESSION_VAR} or `session` in {path:?}"))?;
+    let config: Config = toml::from_str(&text).with_context(|| format!("invalid {path:?}"))?;
+    let session = config
+        .session
+        .with_context(|| format!("no `session` in {path:?}"))?;
+    Ok(session.trim().into())
+}
@@ -0,0 +1,194 @@
+//! Pulling example inputs and their answers out of saved puzzle pages.
+//!
+//! Each part of a puzzle is an `<article>`. Its first `<pre><code>` block is taken to be the
+//! example input, and its last `<code><em>` is the answer for that example. A part without a
+//! block of its own, as is common for part 2, shares the example of the previous part.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+/// Name of the file with the expected answers, next to the example inputs.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+/// An example input with the answers the puzzle text gives for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Expected answers for one example file, keyed by its name in `expected.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+pub type Expected = BTreeMap<String, Answers>;
+
+/// The text between each `start` and the following `end`.
+fn between<'a>(text: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    text.split(start)
+        .skip(1)
+        .filter_map(move |rest| rest.split_once(end).map(|(inside, _)| inside))
+}
+
+/// Removes tags such as the `<em>` highlights inside examples, and decodes entities.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The examples of part 1 and, if present, part 2 of a puzzle page.
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (index, article) in between(html, "<article", "</article>").take(2).enumerate() {
+        let block = between(article, "<pre><code>", "</code></pre>").next();
+        let answer = between(article, "<code><em>", "</em></code>")
+            .last()
+            .map(text);
+
+        let input = block.map(text);
+        let example = match (input, examples.last_mut()) {
+            (Some(input), Some(last)) if input == last.input => last,
+            (None, Some(last)) => last,
+            (Some(input), _) => {
+                examples.push(Example {
+                    input,
+                    answers: Answers::default(),
+                });
+                examples.last_mut().unwrap()
+            }
+            (None, None) => continue,
+        };
+        if index == 0 {
+            example.answers.part1 = answer;
+        } else {
+            example.answers.part2 = answer;
+        }
+    }
+    examples
+}
+
+/// `example.txt`, `example2.txt` and so on, as the solutions already name them.
+pub fn file_name(index: usize) -> String {
+    match index {
+        0 => "example.txt".to_owned(),
+        _ => format!("example{}.txt", index + 1),
+    }
+}
+
+pub fn read_expected(dir: &Path) -> anyhow::Result<Expected> {
+    let path = dir.join(EXPECTED_FILE);
+    if !path.exists() {
+        return Ok(Expected::new());
+    }
+    let text =
+        std::fs::read_to_string(&path).with_context(|| format!("failed to read {path:?}"))?;
+    toml::from_str(&text).with_context(|| format!("invalid {path:?}"))
+}
+
+/// Writes each example into `dir` and merges its answers into `expected.toml`.
+///
+/// Existing example files are left alone if they match, and are an error otherwise, except for
+/// the empty placeholder created by `aoc new`.
+pub fn write(dir: &Path, examples: &[Example]) -> anyhow::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+    let mut expected = read_expected(dir)?;
+    let mut written = vec![];
+    for (index, example) in examples.iter().enumerate() {
+        let name = file_name(index);
+        let path = dir.join(&name);
+        match std::fs::read_to_string(&path) {
+            Ok(existing) if existing == example.input => {}
+            Ok(existing) if !existing.is_empty() => {
+                anyhow::bail!("{path:?} already exists with different contents")
+            }
+            _ => {
+                std::fs::write(&path, &example.input)
+                    .with_context(|| format!("failed to write {path:?}"))?;
+                written.push(path);
+            }
+        }
+        let answers = expected.entry(name).or_default();
+        answers.part1 = example.answers.part1.clone().or(answers.part1.take());
+        answers.part2 = example.answers.part2.clone().or(answers.part2.take());
+    }
+    let path = dir.join(EXPECTED_FILE);
+    std::fs::write(&path, toml::to_string(&expected)?)
+        .with_context(|| format!("failed to write {path:?}"))?;
+    written.push(path);
+    Ok(written)
+}
+
+#[test]
+fn test_text() {
+    assert_eq!(text("a<em>b</em>c"), "abc");
+    assert_eq!(text("x &lt;&amp;lt;&gt; y"), "x <&lt;> y");
+    assert_eq!(text("<em>42</em>"), "42");
+}
+
+#[test]
+fn test_extract() {
+    let html = "<main>\
+        <article class=\"day-desc\"><h2>--- Day 3 ---</h2>\
+        <p>For example:</p><pre><code>xmul(2,4)&amp;mul[3,7]</code></pre>\
+        <p>Only <code>mul(2,4)</code> is real, so <code><em>8</em></code>.</p></article>\
+        <p>Your puzzle answer was <code>123</code>.</p>\
+        <article class=\"day-desc\"><h2>--- Part Two ---</h2>\
+        <pre><code>do()<em>mul(1,1)</em></code></pre>\
+        <p>This time the result is <code><em>1</em></code>.</p></article>\
+        </main>";
+    assert_eq!(
+        extract(html),
+        [
+            Example {
+                input: "xmul(2,4)&mul[3,7]".to_owned(),
+                answers: Answers {
+                    part1: Some("8".to_owned()),
+                    part2: None
+                }
+            },
+            Example {
+                input: "do()mul(1,1)".to_owned(),
+                answers: Answers {
+                    part1: None,
+                    part2: Some("1".to_owned())
+                }
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_extract_shared_example() {
+    let html = "<article><pre><code>3   4\n4   3\n</code></pre><code><em>11</em></code></article>\
+        <article><p>Same list, but <code><em>31</em></code>.</p></article>";
+    assert_eq!(
+        extract(html),
+        [Example {
+            input: "3   4\n4   3\n".to_owned(),
+            answers: Answers {
+                part1: Some("11".to_owned()),
+                part2: Some("31".to_owned())
+            }
+        }]
+    );
+}
@@ -0,0 +1,36 @@
+//! Downloading puzzle inputs into the local cache.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::client::Client;
+
+pub fn cached_input_path(cache_dir: &Path, day: u32) -> PathBuf {
+    cache_dir.join(format!("day{day}")).join("input.txt")
+}
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+/// Downloads the input for `day` unless it is already in the cache.
+///
+/// The client is only created when a download is needed, so a cached day needs no session.
+pub fn fetch(
+    cache_dir: &Path,
+    day: u32,
+    client: impl FnOnce() -> anyhow::Result<Client>,
+) -> anyhow::Result<Fetched> {
+    let path = cached_input_path(cache_dir, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client()?.input(day)?;
+    let dir = path.parent().expect("cached input is in a directory");
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+    std::fs::write(&path, input).with_context(|| format!("failed to write {path:?}"))?;
+    Ok(Fetched::Downloaded(path))
+}
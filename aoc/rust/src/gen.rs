@@ -0,0 +1,325 @@
+//! Random puzzle inputs in the format of each day, for stress tests and benchmarks.
+//!
+//! Inputs made of lines are written as they are generated, in constant memory, so they can be
+//! much larger than memory. The grids of days 4, 6 and 8 are built whole before they are
+//! written. The same seed and size always give the same input.
+
+use std::io::Write;
+
+use aoc2024_common::{direction::Direction, grid::Grid, point::Point};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The size of the real input of each day, in the unit that [`generate`] takes for the day.
+pub fn default_size(day: u32) -> Option<usize> {
+    match day {
+        1 | 2 | 7 => Some(1000),
+        3 => Some(800),
+        4 => Some(140),
+        5 => Some(200),
+        6 => Some(130),
+        8 => Some(50),
+        _ => None,
+    }
+}
+
+/// Writes a random input for `day`.
+///
+/// `size` is the number of lines for days 1, 2 and 7, the number of instructions for day 3,
+/// the number of updates for day 5 and the side of the square grid for days 4, 6 and 8.
+pub fn generate(day: u32, seed: u64, size: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    anyhow::ensure!(size > 0, "the size should be positive");
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    match day {
+        1 => location_ids(rng, size, out),
+        2 => reports(rng, size, out),
+        3 => memory(rng, size, out),
+        4 => word_search(rng, size, out),
+        5 => rules_and_updates(rng, size, out),
+        6 => lab_map(rng, size, out),
+        7 => equations(rng, size, out),
+        8 => antenna_map(rng, size, out),
+        _ => anyhow::bail!("no generator for day {day}"),
+    }
+}
+
+/// Two lists of five-digit IDs, with some IDs of the left list repeated in the right one.
+fn location_ids(rng: &mut impl Rng, lines: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    /// How many of the latest left IDs can be repeated, to keep the memory use constant.
+    const RECENT: usize = 1000;
+    let mut recent = Vec::with_capacity(RECENT);
+    for line in 0..lines {
+        let a = rng.gen_range(10000..100000);
+        let b = match recent.choose(rng) {
+            Some(&b) if rng.gen_bool(0.3) => b,
+            _ => rng.gen_range(10000..100000),
+        };
+        if recent.len() < RECENT {
+            recent.push(a);
+        } else {
+            recent[line % RECENT] = a;
+        }
+        writeln!(out, "{a}   {b}")?;
+    }
+    Ok(())
+}
+
+/// Reports of 5 to 8 levels, mostly safe or one bad level away from safe.
+fn reports(rng: &mut impl Rng, lines: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    for _ in 0..lines {
+        let len = rng.gen_range(5..=8);
+        let sign = if rng.gen() { 1 } else { -1 };
+        let mut level: i32 = if sign > 0 {
+            rng.gen_range(1..20)
+        } else {
+            rng.gen_range(70..99)
+        };
+        let mut levels = vec![];
+        for _ in 0..len {
+            levels.push(level);
+            level += sign * rng.gen_range(1..=3);
+        }
+        if rng.gen_bool(0.5) {
+            let index = rng.gen_range(0..len);
+            levels[index] = rng.gen_range(1..=99);
+        }
+        writeln!(out, "{}", itertools::join(levels, " "))?;
+    }
+    Ok(())
+}
+
+/// Lines of `mul(X,Y)`, `do()` and `don't()` instructions among noise and near misses.
+fn memory(rng: &mut impl Rng, instructions: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    const NOISE: &[&str] = &[
+        "!",
+        "@",
+        "#",
+        "$",
+        "%",
+        "^",
+        "&",
+        "*",
+        "(",
+        ")",
+        "[",
+        "]",
+        "{",
+        "}",
+        "<",
+        ">",
+        ",",
+        "'",
+        " ",
+        "?",
+        "+",
+        "-",
+        "/",
+        ":",
+        ";",
+        "~",
+        "select",
+        "from",
+        "how",
+        "what",
+        "where",
+        "when",
+        "who",
+        "why",
+        "mul",
+        "do",
+        "don't",
+        "mul[3,7]",
+        "mul ( 2 , 4 )",
+        "mul(4*",
+        "mul(6,9!",
+        "?(12,34)",
+        "do_not_mul(5,5)",
+        "mul(1234,5)",
+    ];
+    for index in 0..instructions {
+        match rng.gen_range(0..10) {
+            0 => write!(out, "do()")?,
+            1 => write!(out, "don't()")?,
+            _ => write!(
+                out,
+                "mul({},{})",
+                rng.gen_range(1..1000),
+                rng.gen_range(1..1000)
+            )?,
+        }
+        for _ in 0..rng.gen_range(0..4) {
+            write!(out, "{}", NOISE.choose(rng).unwrap())?;
+        }
+        // The real input is a handful of long lines.
+        if index % 150 == 149 {
+            writeln!(out)?;
+        }
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+/// A square grid of the letters of `XMAS`.
+fn word_search(rng: &mut impl Rng, side: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    for _ in 0..side {
+        let row: String = (0..side)
+            .map(|_| *b"XMAS".choose(rng).unwrap() as char)
+            .collect();
+        writeln!(out, "{row}")?;
+    }
+    Ok(())
+}
+
+/// Rules between every pair of 49 two-digit pages, consistent with one random order, so that
+/// every update can be sorted, followed by updates with an odd number of distinct pages.
+fn rules_and_updates(
+    rng: &mut impl Rng,
+    updates: usize,
+    out: &mut impl Write,
+) -> anyhow::Result<()> {
+    let mut pages: Vec<u32> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(49);
+    for (i, a) in pages.iter().enumerate() {
+        for b in &pages[i + 1..] {
+            writeln!(out, "{a}|{b}")?;
+        }
+    }
+    writeln!(out)?;
+    for _ in 0..updates {
+        let len = 2 * rng.gen_range(2..12) + 1;
+        let mut update: Vec<_> = pages.choose_multiple(rng, len).collect();
+        if rng.gen() {
+            // Keep the rule order, so that about half of the updates are already correct.
+            update.sort_by_key(|&page| pages.iter().position(|p| p == page));
+        }
+        writeln!(out, "{}", itertools::join(update, ","))?;
+    }
+    Ok(())
+}
+
+/// A square map with scattered obstructions and the guard facing up, which the guard walks
+/// out of rather than around in a loop.
+fn lab_map(rng: &mut impl Rng, side: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    loop {
+        let column = rng.gen_range(0..side as i32);
+        let guard = Point::new(rng.gen_range(0..side as i32), column);
+        let mut grid = Grid::new(side, side, '.');
+        for position in grid.positions() {
+            if position == guard {
+                grid[position] = '^';
+            } else if rng.gen_bool(0.05) {
+                grid[position] = '#';
+            }
+        }
+        if leaves(&grid, guard) {
+            write!(out, "{grid}")?;
+            return Ok(());
+        }
+    }
+}
+
+/// Whether the guard starting at `position` facing up walks off the map.
+fn leaves(grid: &Grid<char>, mut position: Point) -> bool {
+    let mut direction = Direction::Up;
+    let mut seen = std::collections::HashSet::new();
+    while seen.insert((position, direction)) {
+        match grid.get(position + direction.vector()) {
+            None => return true,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => position += direction.vector(),
+        }
+    }
+    false
+}
+
+/// Equations of 2 to 12 numbers up to three digits, about half of them solvable.
+///
+/// Test values only have to fit the `u64` that the parser reads them into.
+fn equations(rng: &mut impl Rng, lines: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    let mut line = 0;
+    while line < lines {
+        let len = rng.gen_range(2..=12);
+        let values: Vec<u64> = (0..len).map(|_| rng.gen_range(1..1000)).collect();
+        let target =
+            values[1..]
+                .iter()
+                .try_fold(values[0], |acc, &value| match rng.gen_range(0..3) {
+                    0 => acc.checked_add(value),
+                    1 => acc.checked_mul(value),
+                    _ => acc
+                        .checked_mul(10u64.pow(value.ilog10() + 1))?
+                        .checked_add(value),
+                });
+        // Try again with other numbers if they are too large, leaving room to add one.
+        let Some(mut target) = target.filter(|&target| target < u64::MAX) else {
+            continue;
+        };
+        if rng.gen() {
+            target += 1;
+        }
+        writeln!(out, "{target}: {}", itertools::join(values, " "))?;
+        line += 1;
+    }
+    Ok(())
+}
+
+/// A square map with groups of antennas on the same frequency.
+fn antenna_map(rng: &mut impl Rng, side: usize, out: &mut impl Write) -> anyhow::Result<()> {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut grid = vec![b'.'; side * side];
+    for &frequency in FREQUENCIES.iter().take(side / 4) {
+        for _ in 0..4 {
+            grid[rng.gen_range(0..side * side)] = frequency;
+        }
+    }
+    for row in grid.chunks(side) {
+        out.write_all(row)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_same_seed_same_input() {
+    let input = |seed| {
+        let mut out = vec![];
+        generate(7, seed, 20, &mut out).unwrap();
+        out
+    };
+    assert_eq!(input(1), input(1));
+    assert_ne!(input(1), input(2));
+}
+
+#[test]
+fn test_day5_updates() {
+    let mut out = vec![];
+    generate(5, 0, 10, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let (rules, updates) = text.split_once("\n\n").unwrap();
+    assert_eq!(rules.lines().count(), 49 * 48 / 2);
+    for update in updates.lines() {
+        let pages: Vec<_> = update.split(',').collect();
+        assert_eq!(pages.len() % 2, 1);
+        assert!(pages
+            .iter()
+            .all(|page| pages.iter().filter(|p| p == &page).count() == 1));
+    }
+}
+
+#[test]
+fn test_inputs_are_valid() {
+    // Days fresh from `aoc new` have no generator yet.
+    let generated = crate::registry::ENTRIES
+        .iter()
+        .filter(|entry| default_size(entry.day).is_some());
+    for entry in generated {
+        for seed in 0..5 {
+            let mut out = vec![];
+            generate(entry.day, seed, 30, &mut out).unwrap();
+            if let Err(err) = (entry.run)(&out) {
+                panic!("day {} seed {seed}: {err:#}", entry.day);
+            }
+        }
+    }
+}
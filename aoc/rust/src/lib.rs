@@ -0,0 +1,22 @@
+//! Runs any of the solutions by puzzle day and part.
+
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod client;
+pub mod config;
+pub mod examples;
+pub mod fetch;
+pub mod gen;
+pub mod registry;
+pub mod run_all;
+pub mod scaffold;
+pub mod stats;
+pub mod submit;
+pub mod watch;
+
+/// Root of the Cargo workspace, where the solution directories live.
+pub fn workspace_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.ancestors().nth(2).unwrap().to_path_buf()
+}
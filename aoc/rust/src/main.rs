@@ -0,0 +1,256 @@
+use std::{
+    io::{BufReader, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context;
+use aoc::{
+    answers,
+    client::{Client, BASE_URL},
+    config, examples,
+    fetch::{fetch, Fetched},
+    gen,
+    registry::{self, Entry},
+    run_all::{phase_allocs, run_parallel, table, Budget, Row},
+    scaffold,
+    submit::submit,
+    watch, workspace_dir,
+};
+use aoc2024_common::{
+    alloc::Counting,
+    report::{Format, Hashing, Report},
+};
+use clap::{Args, Parser};
+
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+enum Command {
+    /// Run solutions on their inputs
+    Run(RunArgs),
+    /// List all solutions
+    List,
+    /// Download a puzzle input into the local cache
+    Fetch {
+        /// Puzzle day
+        day: u32,
+    },
+    /// Run a solution on its input and submit the answer
+    Submit {
+        /// Puzzle day
+        day: u32,
+        /// Puzzle part
+        part: u32,
+    },
+    /// Save the examples and their answers from a downloaded puzzle page
+    Examples {
+        /// Puzzle day
+        day: u32,
+        /// The puzzle page saved as HTML
+        page: PathBuf,
+        /// Directory for the example files, instead of the day's `examples/`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Rebuild and rerun a day on its examples and input whenever they or its code change
+    Watch {
+        /// Puzzle day
+        day: u32,
+    },
+    /// Create the crate for a new day from a template and register it
+    New {
+        /// Puzzle day
+        day: u32,
+    },
+    /// Print a random input for a day
+    Gen {
+        /// Puzzle day
+        day: u32,
+        /// Seed for the random numbers; the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Lines, grid side or instructions, depending on the day [default: as the real input]
+        #[arg(long)]
+        size: Option<usize>,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Puzzle day
+    #[arg(required_unless_present = "all")]
+    day: Option<u32>,
+    /// Puzzle part
+    #[arg(required_unless_present = "all")]
+    part: Option<u32>,
+    /// Read the input from this file instead of the solution's `input.txt`
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Solve while reading the input instead of reading it all first, for days that can
+    #[arg(long, conflicts_with = "all")]
+    stream: bool,
+    /// Run every solution on its own input
+    #[arg(long, conflicts_with_all = ["day", "part"])]
+    all: bool,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// With --all, flag parts that take longer than this many seconds
+    #[arg(long, default_value_t = 1.0, requires = "all")]
+    budget: f64,
+    /// With --all, fail parts that allocate more than this many times
+    #[arg(long, requires = "all")]
+    max_allocs: Option<u64>,
+    /// With --all, fail parts that have more than this many MiB allocated at once
+    #[arg(long, requires = "all")]
+    max_peak_mib: Option<f64>,
+    /// With --all, how many parts to run at once [default: number of CPUs]
+    #[arg(long, requires = "all")]
+    jobs: Option<usize>,
+}
+
+fn run(entry: &Entry, path: Option<PathBuf>) -> anyhow::Result<Report> {
+    let path = path.unwrap_or_else(|| entry.input_path());
+    let bytes = std::fs::read(&path).with_context(|| format!("failed to read {path:?}"))?;
+    let timed =
+        (entry.run)(&bytes).with_context(|| format!("day {} part {}", entry.day, entry.part))?;
+    Ok(Report::new(entry.day, entry.part, timed, &bytes))
+}
+
+fn stream(entry: &Entry, path: Option<PathBuf>) -> anyhow::Result<Report> {
+    let stream = entry
+        .stream
+        .with_context(|| format!("day {} has no streaming mode", entry.day))?;
+    let path = path.unwrap_or_else(|| entry.input_path());
+    let file = std::fs::File::open(&path).with_context(|| format!("failed to open {path:?}"))?;
+    let mut input = Hashing::new(BufReader::new(file));
+    let timed =
+        stream(&mut input).with_context(|| format!("day {} part {}", entry.day, entry.part))?;
+    Ok(Report::streamed(
+        entry.day,
+        entry.part,
+        timed,
+        input.finish(),
+    ))
+}
+
+fn main() -> anyhow::Result<()> {
+    match Command::parse() {
+        Command::Run(args) if args.all => {
+            let jobs = args.jobs.unwrap_or_else(|| {
+                std::thread::available_parallelism().map_or(1, |jobs| jobs.get())
+            });
+            let results = run_parallel(registry::ENTRIES, jobs, |entry| run(entry, None));
+            let recorded = answers::read()?;
+            let rows: Vec<_> = registry::ENTRIES
+                .iter()
+                .zip(results)
+                .map(|(entry, result)| Row {
+                    day: entry.day,
+                    part: entry.part,
+                    result: result.and_then(|result| result.map_err(|err| format!("{err:#}"))),
+                    expected: recorded.get(&(entry.day, entry.part)).cloned(),
+                })
+                .collect();
+            let budget = Budget {
+                time: Duration::from_secs_f64(args.budget),
+                allocs: args.max_allocs,
+                peak: args.max_peak_mib.map(|mib| (mib * 1024.0 * 1024.0) as u64),
+            };
+            match args.format {
+                Format::Text => print!("{}", table(&rows, &budget)),
+                Format::Json => {
+                    for row in &rows {
+                        println!("{}", row.to_json(&budget));
+                    }
+                }
+            }
+            let failed = rows.iter().filter(|row| !row.passed(&budget)).count();
+            anyhow::ensure!(failed == 0, "{failed} of {} parts failed", rows.len());
+        }
+        Command::Run(args) => {
+            let (Some(day), Some(part)) = (args.day, args.part) else {
+                unreachable!("clap requires day and part without --all");
+            };
+            let entry = registry::find(day, part)
+                .with_context(|| format!("no solution for day {day} part {part}"))?;
+            let report = if args.stream {
+                stream(entry, args.input)?
+            } else {
+                run(entry, args.input)?
+            };
+            report.print(args.format);
+            if args.format == Format::Text {
+                // On stderr, so that stdout is still just the answer.
+                let phases = [
+                    ("parse", report.parse_allocs),
+                    ("solve", report.solve_allocs),
+                ];
+                for (phase, allocs) in phases {
+                    if let Some(allocs) = allocs {
+                        eprintln!("{}", phase_allocs(phase, &allocs));
+                    }
+                }
+            }
+        }
+        Command::List => {
+            for entry in registry::ENTRIES {
+                println!("day {} part {}: {}", entry.day, entry.part, entry.dir);
+            }
+        }
+        Command::Fetch { day } => {
+            let client = || Ok(Client::new(BASE_URL, config::session()?));
+            match fetch(&config::cache_dir(), day, client)? {
+                Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+                Fetched::Cached(path) => println!("already cached at {}", path.display()),
+            }
+        }
+        Command::Submit { day, part } => {
+            let entry = registry::find(day, part)
+                .with_context(|| format!("no solution for day {day} part {part}"))?;
+            let answer = run(entry, None)?.answer;
+            println!("answer: {answer}");
+            let client = || Ok(Client::new(BASE_URL, config::session()?));
+            let attempt = submit(
+                &config::submissions_path(),
+                day,
+                part,
+                &answer,
+                SystemTime::now(),
+                client,
+            )?;
+            println!("{}", attempt.outcome);
+            if let Some(retry_at) = attempt.retry_at {
+                println!("next attempt in {}s", retry_at - attempt.submitted_at);
+            }
+        }
+        Command::Examples { day, page, out } => {
+            let html = std::fs::read_to_string(&page)
+                .with_context(|| format!("failed to read {page:?}"))?;
+            let found = examples::extract(&html);
+            anyhow::ensure!(!found.is_empty(), "no examples in {page:?}");
+            let out = out.unwrap_or_else(|| workspace_dir().join(format!("day{day}/examples")));
+            for path in examples::write(&out, &found)? {
+                println!("wrote {}", path.display());
+            }
+        }
+        Command::Watch { day } => watch::watch(day)?,
+        Command::New { day } => {
+            for path in scaffold::new_day(&workspace_dir(), day)? {
+                println!("created {}", path.display());
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let size = size
+                .or_else(|| gen::default_size(day))
+                .with_context(|| format!("no generator for day {day}"))?;
+            let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+            gen::generate(day, seed, size, &mut out)?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
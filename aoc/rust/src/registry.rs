@@ -0,0 +1,169 @@
+//! Every solution in the workspace, keyed by puzzle day and part.
+
+use std::{io::BufRead, path::PathBuf};
+
+use aoc2024_common::solution::{Solution, Timed};
+
+use crate::{config, fetch::cached_input_path, workspace_dir};
+
+pub struct Entry {
+    pub day: u32,
+    pub part: u32,
+    /// Directory with the solution crate and its input files.
+    pub dir: &'static str,
+    pub run: fn(&[u8]) -> anyhow::Result<Timed>,
+    /// Solves while reading the input, for days with a streaming mode.
+    pub stream: Option<fn(&mut dyn BufRead) -> anyhow::Result<Timed>>,
+}
+impl Entry {
+    /// Example inputs, with their answers in `expected.toml`.
+    pub fn examples_dir(&self) -> PathBuf {
+        workspace_dir().join(self.dir).join("examples")
+    }
+
+    /// The committed `input.txt`, or else the one downloaded by `aoc fetch`.
+    pub fn input_path(&self) -> PathBuf {
+        let path = workspace_dir().join(self.dir).join("input.txt");
+        if path.exists() {
+            path
+        } else {
+            cached_input_path(&config::cache_dir(), self.day)
+        }
+    }
+}
+
+pub const ENTRIES: &[Entry] = &[
+    Entry {
+        day: 1,
+        part: 1,
+        dir: "day1",
+        run: |input| day1_rust::Day1::solve_timed(input, 1),
+        stream: None,
+    },
+    Entry {
+        day: 1,
+        part: 2,
+        dir: "day1",
+        run: |input| day1_rust::Day1::solve_timed(input, 2),
+        stream: None,
+    },
+    Entry {
+        day: 2,
+        part: 1,
+        dir: "day2",
+        run: |input| day2_rust::Day2::solve_timed(input, 1),
+        stream: Some(|input| day2_rust::Day2::stream_timed(input, 1)),
+    },
+    Entry {
+        day: 2,
+        part: 2,
+        dir: "day2",
+        run: |input| day2_rust::Day2::solve_timed(input, 2),
+        stream: Some(|input| day2_rust::Day2::stream_timed(input, 2)),
+    },
+    Entry {
+        day: 3,
+        part: 1,
+        dir: "day3",
+        run: |input| day3_rust::Day3::solve_timed(input, 1),
+        stream: Some(|input| day3_rust::Day3::stream_timed(input, 1)),
+    },
+    Entry {
+        day: 3,
+        part: 2,
+        dir: "day3",
+        run: |input| day3_rust::Day3::solve_timed(input, 2),
+        stream: Some(|input| day3_rust::Day3::stream_timed(input, 2)),
+    },
+    Entry {
+        day: 4,
+        part: 1,
+        dir: "day4",
+        run: |input| day4_rust::Day4::solve_timed(input, 1),
+        stream: None,
+    },
+    Entry {
+        day: 4,
+        part: 2,
+        dir: "day4",
+        run: |input| day4_rust::Day4::solve_timed(input, 2),
+        stream: None,
+    },
+    Entry {
+        day: 5,
+        part: 1,
+        dir: "day5",
+        run: |input| day5_rust::Day5::solve_timed(input, 1),
+        stream: None,
+    },
+    Entry {
+        day: 5,
+        part: 2,
+        dir: "day5",
+        run: |input| day5_rust::Day5::solve_timed(input, 2),
+        stream: None,
+    },
+    Entry {
+        day: 6,
+        part: 1,
+        dir: "day6",
+        run: |input| day6_rust::Day6::solve_timed(input, 1),
+        stream: None,
+    },
+    Entry {
+        day: 6,
+        part: 2,
+        dir: "day6",
+        run: |input| day6_rust::Day6::solve_timed(input, 2),
+        stream: None,
+    },
+    Entry {
+        day: 7,
+        part: 1,
+        dir: "day7",
+        run: |input| day7_rust::Day7::solve_timed(input, 1),
+        stream: Some(|input| day7_rust::Day7::stream_timed(input, 1)),
+    },
+    Entry {
+        day: 7,
+        part: 2,
+        dir: "day7",
+        run: |input| day7_rust::Day7::solve_timed(input, 2),
+        stream: Some(|input| day7_rust::Day7::stream_timed(input, 2)),
+    },
+    Entry {
+        day: 8,
+        part: 1,
+        dir: "day8",
+        run: |input| day8_rust::Day8::solve_timed(input, 1),
+        stream: None,
+    },
+    Entry {
+        day: 8,
+        part: 2,
+        dir: "day8",
+        run: |input| day8_rust::Day8::solve_timed(input, 2),
+        stream: None,
+    },
+];
+
+pub fn find(day: u32, part: u32) -> Option<&'static Entry> {
+    ENTRIES
+        .iter()
+        .find(|entry| entry.day == day && entry.part == part)
+}
+
+#[test]
+fn every_part_registered_once() {
+    let days: std::collections::BTreeSet<u32> = ENTRIES.iter().map(|entry| entry.day).collect();
+    for &day in &days {
+        for part in 1..=2 {
+            let count = ENTRIES
+                .iter()
+                .filter(|entry| entry.day == day && entry.part == part)
+                .count();
+            assert_eq!(count, 1, "day {day} part {part}");
+        }
+    }
+    assert_eq!(ENTRIES.len(), 2 * days.len());
+}
@@ -0,0 +1,378 @@
+//! Running every solution at once and summarizing the results in a table.
+
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use aoc2024_common::{alloc::Allocs, report::Report};
+use serde::Serialize;
+
+use crate::registry::Entry;
+
+/// Runs `run` on every entry using `jobs` worker threads, returning results in entry order.
+///
+/// A panicking entry, such as a day whose parts are still `todo!()`, gives the panic message
+/// as its error instead of taking the other entries down with it.
+pub fn run_parallel<T: Send>(
+    entries: &[Entry],
+    jobs: usize,
+    run: impl Fn(&Entry) -> T + Sync,
+) -> Vec<Result<T, String>> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(entries.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(entry) = entries.get(index) else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| run(entry)))
+                    .map_err(|payload| format!("panicked: {}", panic_message(&*payload)));
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The message of a panic, which is a string unless it was raised with `panic_any`.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// One line of the summary table.
+pub struct Row {
+    pub day: u32,
+    pub part: u32,
+    /// The report, or the error message if the part failed to run.
+    pub result: Result<Report, String>,
+    /// The answer recorded in `answers.toml`, if any.
+    pub expected: Option<String>,
+}
+impl Row {
+    /// Ran within the memory budget, and matched the recorded answer or has none recorded yet.
+    pub fn passed(&self, budget: &Budget) -> bool {
+        match (&self.result, &self.expected) {
+            (Ok(report), _) if budget.exceeded(report).is_some() => false,
+            (Ok(report), Some(expected)) => report.answer == *expected,
+            (Ok(_), None) => true,
+            (Err(_), _) => false,
+        }
+    }
+
+    /// Whether the part took longer than the time budget.
+    pub fn slow(&self, budget: &Budget) -> bool {
+        self.result.as_ref().is_ok_and(|report| {
+            Duration::from_nanos(report.parse_ns + report.solve_ns) > budget.time
+        })
+    }
+
+    /// `pass`, or why the part failed.
+    pub fn status(&self, budget: &Budget) -> String {
+        match (&self.result, &self.expected) {
+            (Err(err), _) => format!("error: {err}"),
+            (Ok(report), _) if budget.exceeded(report).is_some() => {
+                format!("FAIL ({})", budget.exceeded(report).unwrap())
+            }
+            (Ok(_), None) => "no recorded answer".to_owned(),
+            (Ok(_), Some(_)) if self.passed(budget) => "pass".to_owned(),
+            (Ok(_), Some(expected)) => format!("FAIL (expected {expected})"),
+        }
+    }
+
+    /// The report with the check against the budget and recorded answer, or the error, as one
+    /// line of JSON.
+    pub fn to_json(&self, budget: &Budget) -> String {
+        let outcome = match &self.result {
+            Ok(report) => Outcome::Report(report),
+            Err(error) => Outcome::Error {
+                day: self.day,
+                part: self.part,
+                error,
+            },
+        };
+        let row = JsonRow {
+            outcome,
+            expected: self.expected.as_deref(),
+            passed: self.passed(budget),
+            over_budget: self.slow(budget),
+            status: self.status(budget),
+        };
+        serde_json::to_string(&row).expect("a row is always valid JSON")
+    }
+}
+
+/// A row as printed by `run --all --format json`.
+#[derive(Serialize)]
+struct JsonRow<'a> {
+    #[serde(flatten)]
+    outcome: Outcome<'a>,
+    expected: Option<&'a str>,
+    passed: bool,
+    over_budget: bool,
+    status: String,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Outcome<'a> {
+    Report(&'a Report),
+    Error { day: u32, part: u32, error: &'a str },
+}
+
+/// Limits on the resources of each part.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Budget {
+    /// Time to parse and solve, which is only flagged when exceeded.
+    pub time: Duration,
+    /// Allocations while parsing and solving.
+    pub allocs: Option<u64>,
+    /// Most bytes live at once while parsing or while solving.
+    pub peak: Option<u64>,
+}
+impl Budget {
+    /// Which of the allocation limits the part went over, if any.
+    pub fn exceeded(&self, report: &Report) -> Option<&'static str> {
+        let allocs = report.allocs()?;
+        if self.allocs.is_some_and(|max| allocs.count > max) {
+            Some("too many allocations")
+        } else if self.peak.is_some_and(|max| allocs.peak > max) {
+            Some("too much memory")
+        } else {
+            None
+        }
+    }
+}
+
+/// `512 B`, `1.5 KiB`, `12.0 MiB` and so on.
+pub fn human_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64;
+    for unit in ["KiB", "MiB", "GiB"] {
+        value /= 1024.0;
+        if value < 1024.0 || unit == "GiB" {
+            return format!("{value:.1} {unit}");
+        }
+    }
+    unreachable!()
+}
+
+/// `parse: 10 allocs, 29.6 KiB allocated, 21.7 KiB peak`, for one phase of a part.
+pub fn phase_allocs(phase: &str, allocs: &Allocs) -> String {
+    format!(
+        "{phase}: {} allocs, {} allocated, {} peak",
+        allocs.count,
+        human_bytes(allocs.bytes),
+        human_bytes(allocs.peak)
+    )
+}
+
+/// The count, bytes and peak columns of one phase, or dashes if it wasn't counted.
+fn alloc_columns(allocs: Option<Allocs>) -> [String; 3] {
+    match allocs {
+        Some(allocs) => [
+            allocs.count.to_string(),
+            human_bytes(allocs.bytes),
+            human_bytes(allocs.peak),
+        ],
+        None => ["-".to_owned(), "-".to_owned(), "-".to_owned()],
+    }
+}
+
+/// Renders the rows, flagging any part that went over the budget.
+pub fn table(rows: &[Row], budget: &Budget) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:>3} {:>4} {:>16} {:>11} {:>12} {:>11} {:>10} {:>12} {:>11} {:>10}  status",
+        "day",
+        "part",
+        "answer",
+        "time",
+        "parse allocs",
+        "bytes",
+        "peak",
+        "solve allocs",
+        "bytes",
+        "peak"
+    )
+    .unwrap();
+    for row in rows {
+        let (answer, time) = match &row.result {
+            Ok(report) => {
+                let time = Duration::from_nanos(report.parse_ns + report.solve_ns);
+                (report.answer.as_str(), format!("{time:.2?}"))
+            }
+            Err(_) => ("-", "-".to_owned()),
+        };
+        let report = row.result.as_ref().ok();
+        let [parse_allocs, parse_bytes, parse_peak] =
+            alloc_columns(report.and_then(|report| report.parse_allocs));
+        let [solve_allocs, solve_bytes, solve_peak] =
+            alloc_columns(report.and_then(|report| report.solve_allocs));
+        let status = row.status(budget);
+        let slow = if row.slow(budget) {
+            "  over budget"
+        } else {
+            ""
+        };
+        writeln!(
+            out,
+            "{:>3} {:>4} {answer:>16} {time:>11} {parse_allocs:>12} {parse_bytes:>11} {parse_peak:>10} \
+             {solve_allocs:>12} {solve_bytes:>11} {solve_peak:>10}  {status}{slow}",
+            row.day, row.part
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[test]
+fn test_run_parallel() {
+    let entries: Vec<_> = (1..=20)
+        .map(|day| Entry {
+            day,
+            part: 1,
+            dir: "",
+            run: |_| unreachable!(),
+            stream: None,
+        })
+        .collect();
+    let days = run_parallel(&entries, 4, |entry| entry.day);
+    assert_eq!(days, (1..=20).map(Ok).collect::<Vec<_>>());
+
+    let days = run_parallel(&entries, 4, |entry| match entry.day {
+        3 => todo!(),
+        7 => panic!("day {} is broken", entry.day),
+        day => day,
+    });
+    assert_eq!(days[1], Ok(2));
+    assert_eq!(days[2], Err("panicked: not yet implemented".to_owned()));
+    assert_eq!(days[6], Err("panicked: day 7 is broken".to_owned()));
+}
+
+#[test]
+fn test_human_bytes() {
+    assert_eq!(human_bytes(0), "0 B");
+    assert_eq!(human_bytes(512), "512 B");
+    assert_eq!(human_bytes(1023), "1023 B");
+    assert_eq!(human_bytes(1024), "1.0 KiB");
+    assert_eq!(human_bytes(1536), "1.5 KiB");
+    assert_eq!(human_bytes(12 << 20), "12.0 MiB");
+    assert_eq!(human_bytes(3 << 40), "3072.0 GiB");
+}
+
+#[test]
+fn test_phase_allocs() {
+    let allocs = Allocs {
+        count: 10,
+        bytes: 30352,
+        peak: 512,
+    };
+    assert_eq!(
+        phase_allocs("parse", &allocs),
+        "parse: 10 allocs, 29.6 KiB allocated, 512 B peak"
+    );
+}
+
+#[test]
+fn test_table() {
+    let report = |answer: &str, ms: u64, peak: u64| Report {
+        day: 0,
+        part: 0,
+        answer: answer.to_owned(),
+        parse_ns: 0,
+        solve_ns: ms * 1_000_000,
+        input_sha256: String::new(),
+        parse_allocs: Some(Allocs {
+            count: 10,
+            bytes: 4096,
+            peak: 4096,
+        }),
+        solve_allocs: Some(Allocs {
+            count: 2,
+            bytes: peak,
+            peak,
+        }),
+    };
+    let row = |day, part, result, expected: Option<&str>| Row {
+        day,
+        part,
+        result,
+        expected: expected.map(str::to_owned),
+    };
+    let rows = [
+        row(1, 1, Ok(report("11", 2, 0)), Some("11")),
+        row(1, 2, Ok(report("30", 3, 0)), Some("31")),
+        row(12, 1, Ok(report("1930", 1500, 0)), Some("1930")),
+        row(12, 2, Ok(report("1206", 4, 0)), None),
+        row(13, 1, Err("failed to read input".to_owned()), None),
+        row(13, 2, Ok(report("480", 4, 3 << 20)), Some("480")),
+    ];
+    let budget = Budget {
+        time: Duration::from_secs(1),
+        allocs: Some(100),
+        peak: Some(1 << 20),
+    };
+    assert!(rows[0].passed(&budget) && !rows[1].passed(&budget));
+    assert!(rows[3].passed(&budget) && !rows[4].passed(&budget) && !rows[5].passed(&budget));
+    insta::assert_snapshot!(table(&rows, &budget), @r"
+    day part           answer        time parse allocs       bytes       peak solve allocs       bytes       peak  status
+      1    1               11      2.00ms           10     4.0 KiB    4.0 KiB            2         0 B        0 B  pass
+      1    2               30      3.00ms           10     4.0 KiB    4.0 KiB            2         0 B        0 B  FAIL (expected 31)
+     12    1             1930       1.50s           10     4.0 KiB    4.0 KiB            2         0 B        0 B  pass  over budget
+     12    2             1206      4.00ms           10     4.0 KiB    4.0 KiB            2         0 B        0 B  no recorded answer
+     13    1                -           -            -           -          -            -           -          -  error: failed to read input
+     13    2              480      4.00ms           10     4.0 KiB    4.0 KiB            2     3.0 MiB    3.0 MiB  FAIL (too much memory)
+    ");
+}
+
+#[test]
+fn test_row_to_json() {
+    let budget = Budget {
+        time: Duration::from_secs(1),
+        ..Budget::default()
+    };
+    let slow = Report {
+        day: 12,
+        part: 1,
+        answer: "1930".to_owned(),
+        parse_ns: 0,
+        solve_ns: 1_500_000_000,
+        input_sha256: "ab".to_owned(),
+        parse_allocs: None,
+        solve_allocs: None,
+    };
+    let row = Row {
+        day: 12,
+        part: 1,
+        result: Ok(slow),
+        expected: Some("1931".to_owned()),
+    };
+    insta::assert_snapshot!(row.to_json(&budget), @r#"{"day":12,"part":1,"answer":"1930","parse_ns":0,"solve_ns":1500000000,"input_sha256":"ab","expected":"1931","passed":false,"over_budget":true,"status":"FAIL (expected 1931)"}"#);
+    let row = Row {
+        day: 13,
+        part: 1,
+        result: Err("panicked: not yet implemented".to_owned()),
+        expected: None,
+    };
+    insta::assert_snapshot!(row.to_json(&budget), @r#"{"day":13,"part":1,"error":"panicked: not yet implemented","expected":null,"passed":false,"over_budget":false,"status":"error: panicked: not yet implemented"}"#);
+}
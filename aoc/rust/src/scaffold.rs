@@ -0,0 +1,76 @@
+//! Generating the crate for a new day and registering it with the runner.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const ENTRIES_RS: &str = include_str!("../templates/entries.rs.tmpl");
+
+fn render(template: &str, day: u32) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+fn write_new(path: &Path, contents: &str) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("failed to write {path:?}"))
+}
+
+/// Inserts `line` after the last line starting with `prefix`.
+fn insert_after_last(text: &str, prefix: &str, line: &str) -> anyhow::Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let idx = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .with_context(|| format!("no line starting with {prefix:?}"))?;
+    let mut lines = lines;
+    lines.insert(idx + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> anyhow::Result<String>) -> anyhow::Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {path:?}"))?;
+    let text = f(&text).with_context(|| format!("failed to edit {path:?}"))?;
+    std::fs::write(path, text).with_context(|| format!("failed to write {path:?}"))
+}
+
+/// Creates `day{day}/` in the workspace and adds it to the runner's registry.
+///
+/// Returns the paths of the files that were created.
+pub fn new_day(workspace: &Path, day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    anyhow::ensure!((1..=25).contains(&day), "day {day} is not between 1 and 25");
+    let dir = workspace.join(format!("day{day}"));
+    anyhow::ensure!(!dir.exists(), "{dir:?} already exists");
+
+    let files = [
+        (dir.join("rust/Cargo.toml"), render(CARGO_TOML, day)),
+        (dir.join("rust/src/lib.rs"), render(LIB_RS, day)),
+        (dir.join("rust/src/main.rs"), render(MAIN_RS, day)),
+        (dir.join("examples/example.txt"), String::new()),
+        (dir.join("examples/expected.toml"), String::new()),
+    ];
+    for (path, contents) in &files {
+        write_new(path, contents)?;
+    }
+
+    let krate = format!("day{day}_rust");
+    edit(&workspace.join("Cargo.toml"), |text| {
+        let line = format!("{krate} = {{ path = \"day{day}/rust\" }}");
+        insert_after_last(text, "day", &line)
+    })?;
+    edit(&workspace.join("aoc/rust/Cargo.toml"), |text| {
+        insert_after_last(text, "day", &format!("{krate}.workspace = true"))
+    })?;
+    edit(&workspace.join("aoc/rust/src/registry.rs"), |text| {
+        let (head, tail) = text
+            .split_once("\n];\n")
+            .context("no end of the ENTRIES list")?;
+        Ok(format!("{head}\n{}];\n{tail}", render(ENTRIES_RS, day)))
+    })?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
@@ -0,0 +1,59 @@
+//! Summary statistics over repeated timings.
+
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+impl Summary {
+    /// Summarizes a non-empty list of timings.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            samples: n,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            max: sorted[n - 1],
+        }
+    }
+}
+
+#[test]
+fn test_summary() {
+    let ms = Duration::from_millis;
+    let summary = Summary::new(&[ms(4), ms(2), ms(6), ms(4)]);
+    assert_eq!(summary.samples, 4);
+    assert_eq!(summary.mean, ms(4));
+    assert_eq!(summary.median, ms(4));
+    assert_eq!(summary.min, ms(2));
+    assert_eq!(summary.max, ms(6));
+    assert_eq!(summary.std_dev.as_micros(), 1414);
+
+    assert_eq!(Summary::new(&[ms(1), ms(3), ms(9)]).median, ms(3));
+}
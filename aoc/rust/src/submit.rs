@@ -0,0 +1,301 @@
+//! Submitting answers, with a local log of every attempt.
+//!
+//! The log lets us refuse answers the site has already rejected, and wait out the cooldown it
+//! imposes after each wrong answer instead of hammering it.
+
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Not checked because the previous answer was submitted too recently.
+    TooRecent,
+    /// Not checked because the part is already solved or not unlocked yet.
+    WrongLevel,
+}
+impl Outcome {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wrong => "wrong",
+            Self::TooRecent => "submitted too recently",
+            Self::WrongLevel => "already solved or not unlocked",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The verdict together with how long the site wants us to wait before the next attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+}
+
+/// Reads the verdict out of the HTML page returned for a submission.
+pub fn parse_response(html: &str) -> anyhow::Result<Response> {
+    let outcome = if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if html.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if html.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::TooRecent
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        anyhow::bail!("unrecognized response: {html}");
+    };
+    Ok(Response {
+        outcome,
+        wait: parse_wait(html),
+    })
+}
+
+/// `You have 1m 5s left to wait` or `please wait 5 minutes before trying again`.
+fn parse_wait(html: &str) -> Option<Duration> {
+    if let Some((_, rest)) = html.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        return left.split(' ').try_fold(Duration::ZERO, |total, part| {
+            let (number, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(number * seconds))
+        });
+    }
+    let (_, rest) = html.split_once("wait ")?;
+    let (amount, _) = rest.split_once(" before trying again")?;
+    let (number, unit) = amount.split_once(' ')?;
+    let number = match number {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    let seconds = match unit {
+        "second" | "seconds" => 1,
+        "minute" | "minutes" => 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(number * seconds))
+}
+
+/// One submission as recorded in the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+    /// No further answers for this part before this time, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct LogFile {
+    #[serde(default)]
+    attempt: Vec<Attempt>,
+}
+
+/// Every attempt so far, read from `path`, which may not exist yet.
+pub fn read_log(path: &Path) -> anyhow::Result<Vec<Attempt>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let text = std::fs::read_to_string(path).with_context(|| format!("failed to read {path:?}"))?;
+    let log: LogFile = toml::from_str(&text).with_context(|| format!("invalid {path:?}"))?;
+    Ok(log.attempt)
+}
+
+fn append_log(path: &Path, attempt: Attempt) -> anyhow::Result<()> {
+    let mut log = LogFile {
+        attempt: read_log(path)?,
+    };
+    log.attempt.push(attempt);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("failed to create {dir:?}"))?;
+    }
+    std::fs::write(path, toml::to_string(&log)?)
+        .with_context(|| format!("failed to write {path:?}"))
+}
+
+/// Why an answer is not worth sending, judging by earlier attempts.
+fn refusal(log: &[Attempt], day: u32, part: u32, answer: &str, now: u64) -> Option<String> {
+    let attempts = || log.iter().filter(|a| a.day == day && a.part == part);
+
+    if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+        return Some(format!("already solved with {}", correct.answer));
+    }
+    if let Some(wrong) = attempts().find(|a| a.answer == answer && a.outcome.is_wrong()) {
+        return Some(format!(
+            "{answer} was already rejected as {}",
+            wrong.outcome
+        ));
+    }
+    if let Ok(number) = answer.parse::<i128>() {
+        for attempt in attempts() {
+            let Ok(known) = attempt.answer.parse::<i128>() else {
+                continue;
+            };
+            match attempt.outcome {
+                Outcome::TooHigh if number >= known => {
+                    return Some(format!("{answer} is not below {known}, which is too high"));
+                }
+                Outcome::TooLow if number <= known => {
+                    return Some(format!("{answer} is not above {known}, which is too low"));
+                }
+                _ => {}
+            }
+        }
+    }
+    if let Some(retry_at) = attempts().filter_map(|a| a.retry_at).max() {
+        if retry_at > now {
+            return Some(format!("cooling down for {}s more", retry_at - now));
+        }
+    }
+    None
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .expect("clock is after 1970")
+        .as_secs()
+}
+
+/// Submits `answer` unless the log shows it is pointless, and records the outcome.
+///
+/// The client is only created when the answer is actually sent.
+pub fn submit(
+    log_path: &Path,
+    day: u32,
+    part: u32,
+    answer: &str,
+    now: SystemTime,
+    client: impl FnOnce() -> anyhow::Result<Client>,
+) -> anyhow::Result<Attempt> {
+    let submitted_at = unix_seconds(now);
+    if let Some(reason) = refusal(&read_log(log_path)?, day, part, answer, submitted_at) {
+        anyhow::bail!("not submitting day {day} part {part}: {reason}");
+    }
+
+    let html = client()?.answer(day, part, answer)?;
+    let response = parse_response(&html)?;
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.to_owned(),
+        outcome: response.outcome,
+        submitted_at,
+        retry_at: response.wait.map(|wait| submitted_at + wait.as_secs()),
+    };
+    append_log(log_path, attempt.clone())?;
+    Ok(attempt)
+}
+
+#[test]
+fn test_parse_response() {
+    let response = |html| parse_response(html).unwrap();
+    assert_eq!(
+        response("<p>That's the right answer!  You are one gold star closer.</p>"),
+        Response {
+            outcome: Outcome::Correct,
+            wait: None
+        }
+    );
+    assert_eq!(
+        response(
+            "<p>That's not the right answer; your answer is too high.  \
+             Please wait one minute before trying again.</p>"
+        ),
+        Response {
+            outcome: Outcome::TooHigh,
+            wait: Some(Duration::from_secs(60))
+        }
+    );
+    assert_eq!(
+        response(
+            "<p>That's not the right answer; your answer is too low.  \
+             please wait 5 minutes before trying again.</p>"
+        ),
+        Response {
+            outcome: Outcome::TooLow,
+            wait: Some(Duration::from_secs(300))
+        }
+    );
+    assert_eq!(
+        response(
+            "<p>You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.</p>"
+        ),
+        Response {
+            outcome: Outcome::TooRecent,
+            wait: Some(Duration::from_secs(65))
+        }
+    );
+    assert_eq!(
+        response(
+            "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+        )
+        .outcome,
+        Outcome::WrongLevel
+    );
+    assert!(parse_response("<p>Puzzle inputs differ by user.</p>").is_err());
+}
+
+#[test]
+fn test_refusal() {
+    let attempt = |answer: &str, outcome, retry_at| Attempt {
+        day: 1,
+        part: 1,
+        answer: answer.to_owned(),
+        outcome,
+        submitted_at: 100,
+        retry_at,
+    };
+    let log = [
+        attempt("50", Outcome::TooHigh, Some(160)),
+        attempt("10", Outcome::TooLow, None),
+        attempt("abc", Outcome::Wrong, None),
+    ];
+    assert!(refusal(&log, 1, 1, "abc", 200).is_some());
+    assert!(refusal(&log, 1, 1, "50", 200).is_some());
+    assert!(refusal(&log, 1, 1, "70", 200).is_some());
+    assert!(refusal(&log, 1, 1, "10", 200).is_some());
+    assert!(refusal(&log, 1, 1, "30", 150).is_some());
+    assert_eq!(refusal(&log, 1, 1, "30", 200), None);
+    assert_eq!(refusal(&log, 1, 2, "abc", 150), None);
+
+    let solved = [attempt("30", Outcome::Correct, None)];
+    assert!(refusal(&solved, 1, 1, "31", 200).is_some());
+}
@@ -0,0 +1,203 @@
+//! Re-running a day whenever its code or inputs change.
+//!
+//! The runner has the solutions compiled in, so a changed solution is rebuilt and run as its
+//! own binary, with `--format json` for answers we can compare between runs.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context;
+use aoc2024_common::report::Report;
+
+use crate::{examples, registry, workspace_dir};
+
+/// How often to look for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Answers by input file and part.
+pub type Answers = BTreeMap<(String, u32), String>;
+
+/// Modification times of every file under `paths`, which may be files or directories.
+pub fn mtimes(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            times.insert(path, modified);
+        }
+    }
+    times
+}
+
+/// Lists the answers, marking the ones that changed since `previous` like a diff, and the
+/// ones that differ from the expected example answers.
+pub fn render(previous: Option<&Answers>, answers: &Answers, expected: &Answers) -> String {
+    let mut out = String::new();
+    for (key @ (file, part), answer) in answers {
+        let line = format!("{file} part {part}: {answer}");
+        let mismatch = expected
+            .get(key)
+            .filter(|&expected| expected != answer)
+            .map(|expected| format!(" (expected {expected})"))
+            .unwrap_or_default();
+        match previous.and_then(|previous| previous.get(key)) {
+            Some(old) if old != answer => {
+                writeln!(out, "- {file} part {part}: {old}").unwrap();
+                writeln!(out, "+ {line}{mismatch}").unwrap();
+            }
+            _ => writeln!(out, "  {line}{mismatch}").unwrap(),
+        }
+    }
+    out
+}
+
+/// The files and directories whose changes affect the day's answers.
+fn watched_paths(day_dir: &Path, input: &Path) -> Vec<PathBuf> {
+    vec![
+        day_dir.join("rust/src"),
+        day_dir.join("rust/Cargo.toml"),
+        day_dir.join("examples"),
+        input.to_path_buf(),
+        workspace_dir().join("common/rust/src"),
+    ]
+}
+
+/// Example files by name, then the real input, labelled relative to the day.
+fn inputs(day_dir: &Path, input: &Path) -> Vec<(String, PathBuf)> {
+    let mut files: Vec<_> = std::fs::read_dir(day_dir.join("examples"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".txt"))
+        .map(|name| {
+            (
+                format!("examples/{name}"),
+                day_dir.join("examples").join(name),
+            )
+        })
+        .collect();
+    files.sort();
+    files.push(("input.txt".to_owned(), input.to_path_buf()));
+    files
+}
+
+fn cargo(subcommand: &str, day: u32) -> Command {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command
+        .current_dir(workspace_dir())
+        .args([subcommand, "--quiet", "--release", "--package"])
+        .arg(format!("day{day}_rust"));
+    command
+}
+
+/// Runs the day's binary on one input file.
+fn run_day(day: u32, path: &Path) -> anyhow::Result<Vec<Report>> {
+    let stdin = std::fs::File::open(path).with_context(|| format!("failed to open {path:?}"))?;
+    let output = cargo("run", day)
+        .args(["--", "--format", "json"])
+        .stdin(stdin)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("failed to run cargo")?;
+    anyhow::ensure!(output.status.success(), "failed on {path:?}");
+    String::from_utf8(output.stdout)?
+        .lines()
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+fn run_all(day: u32, day_dir: &Path, input: &Path) -> anyhow::Result<Answers> {
+    let status = cargo("build", day)
+        .status()
+        .context("failed to run cargo")?;
+    anyhow::ensure!(status.success(), "build failed");
+
+    let mut answers = Answers::new();
+    for (label, path) in inputs(day_dir, input) {
+        for report in run_day(day, &path)? {
+            answers.insert((label.clone(), report.part), report.answer);
+        }
+    }
+    Ok(answers)
+}
+
+/// Expected example answers, keyed like [`Answers`].
+fn expected(day_dir: &Path) -> anyhow::Result<Answers> {
+    let mut expected = Answers::new();
+    for (file, answers) in examples::read_expected(&day_dir.join("examples"))? {
+        let label = format!("examples/{file}");
+        for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+            if let Some(answer) = answer {
+                expected.insert((label.clone(), part), answer);
+            }
+        }
+    }
+    Ok(expected)
+}
+
+/// Rebuilds and reruns the day every time a watched file changes. Never returns on success.
+pub fn watch(day: u32) -> anyhow::Result<()> {
+    let entry = registry::find(day, 1).with_context(|| format!("no solution for day {day}"))?;
+    let day_dir = workspace_dir().join(entry.dir);
+    let input = entry.input_path();
+    let paths = watched_paths(&day_dir, &input);
+
+    let mut seen = None;
+    let mut previous = None;
+    loop {
+        let times = mtimes(&paths);
+        if seen.as_ref() != Some(&times) {
+            seen = Some(times);
+            println!("--- day {day} ---");
+            match run_all(day, &day_dir, &input) {
+                Ok(answers) => {
+                    // A half-edited `expected.toml` is reported like a failed build.
+                    let expected = expected(&day_dir).unwrap_or_else(|err| {
+                        println!("{err:#}");
+                        Answers::new()
+                    });
+                    print!("{}", render(previous.as_ref(), &answers, &expected));
+                    previous = Some(answers);
+                }
+                Err(err) => println!("{err:#}"),
+            }
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+fn answers(list: &[(&str, u32, &str)]) -> Answers {
+    list.iter()
+        .map(|&(file, part, answer)| ((file.to_owned(), part), answer.to_owned()))
+        .collect()
+}
+
+#[test]
+fn test_render() {
+    let previous = answers(&[("examples/example.txt", 1, "11"), ("input.txt", 1, "7")]);
+    let current = answers(&[
+        ("examples/example.txt", 1, "12"),
+        ("input.txt", 1, "7"),
+        ("input.txt", 2, "9"),
+    ]);
+    let expected = answers(&[("examples/example.txt", 1, "11")]);
+    insta::assert_snapshot!(render(Some(&previous), &current, &expected), @r"
+    - examples/example.txt part 1: 11
+    + examples/example.txt part 1: 12 (expected 11)
+      input.txt part 1: 7
+      input.txt part 2: 9
+    ");
+}
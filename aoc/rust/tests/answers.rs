@@ -0,0 +1,16 @@
+//! Runs every solution on its real input and compares against `answers.toml`.
+
+use aoc::registry;
+
+fn check(day: u32, part: u32, expected: &str) {
+    let entry = registry::find(day, part).expect("no solution registered");
+    let input = std::fs::read(entry.input_path()).expect("failed to read input");
+    let timed = (entry.run)(&input).expect("failed to solve");
+    assert_eq!(timed.answer, expected, "day {day} part {part}");
+    if let Some(stream) = entry.stream {
+        let timed = stream(&mut input.as_slice()).expect("failed to solve while streaming");
+        assert_eq!(timed.answer, expected, "day {day} part {part}, streaming");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
@@ -0,0 +1,22 @@
+//! Runs every solution on each file in its `examples/` and compares against `expected.toml`.
+
+use aoc::registry;
+
+fn check(day: u32, file: &str, part1: Option<&str>, part2: Option<&str>) {
+    assert!(
+        part1.is_some() || part2.is_some(),
+        "no answers for {file} in day{day}/examples/expected.toml"
+    );
+    for (part, expected) in [(1, part1), (2, part2)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let entry = registry::find(day, part).expect("no solution registered");
+        let path = entry.examples_dir().join(file);
+        let input = std::fs::read(&path).expect("failed to read example");
+        let timed = (entry.run)(&input).expect("failed to solve");
+        assert_eq!(timed.answer, expected, "day {day} part {part} on {file}");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
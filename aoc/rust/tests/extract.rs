@@ -0,0 +1,51 @@
+mod common;
+
+use aoc::examples::{read_expected, write, Answers, Example};
+use common::temp_dir;
+
+fn example(input: &str, part1: Option<&str>, part2: Option<&str>) -> Example {
+    Example {
+        input: input.to_owned(),
+        answers: Answers {
+            part1: part1.map(str::to_owned),
+            part2: part2.map(str::to_owned),
+        },
+    }
+}
+
+#[test]
+fn writes_examples_and_merges_answers() -> anyhow::Result<()> {
+    let dir = temp_dir("examples");
+    // The placeholder left by `aoc new` is replaced.
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("example.txt"), "")?;
+
+    let written = write(&dir, &[example("1 2\n", Some("3"), None)])?;
+    assert_eq!(
+        written,
+        [dir.join("example.txt"), dir.join("expected.toml")]
+    );
+    assert_eq!(std::fs::read_to_string(dir.join("example.txt"))?, "1 2\n");
+
+    // Saving the page again after solving part 1 adds the part 2 answer and a new example.
+    let examples = [
+        example("1 2\n", Some("3"), Some("2")),
+        example("5 6\n", None, Some("30")),
+    ];
+    let written = write(&dir, &examples)?;
+    assert_eq!(
+        written,
+        [dir.join("example2.txt"), dir.join("expected.toml")]
+    );
+
+    let expected = read_expected(&dir)?;
+    assert_eq!(expected.len(), 2);
+    assert_eq!(expected["example.txt"], examples[0].answers);
+    assert_eq!(expected["example2.txt"], examples[1].answers);
+
+    let err = write(&dir, &[example("9 9\n", None, None)]).unwrap_err();
+    assert!(err.to_string().contains("different contents"), "{err}");
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
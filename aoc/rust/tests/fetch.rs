@@ -0,0 +1,44 @@
+mod common;
+
+use aoc::{
+    client::{Client, USER_AGENT},
+    fetch::{cached_input_path, fetch, Fetched},
+};
+use common::{temp_dir, StubServer};
+
+#[test]
+fn downloads_once_then_uses_cache() -> anyhow::Result<()> {
+    let server = StubServer::start(200, "1   2\n3   4\n");
+    let cache = temp_dir("fetch");
+    let client = || Ok(Client::new(&server.url, "secret"));
+
+    let Fetched::Downloaded(path) = fetch(&cache, 1, client)? else {
+        panic!("expected a download");
+    };
+    assert_eq!(path, cached_input_path(&cache, 1));
+    assert_eq!(std::fs::read_to_string(&path)?, "1   2\n3   4\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].head.starts_with("GET /2024/day/1/input "));
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+
+    let client = || -> anyhow::Result<Client> { panic!("cached day must not connect") };
+    assert!(matches!(fetch(&cache, 1, client)?, Fetched::Cached(_)));
+    assert_eq!(server.requests().len(), 1);
+
+    std::fs::remove_dir_all(cache)?;
+    Ok(())
+}
+
+#[test]
+fn reports_errors_without_caching() {
+    let server = StubServer::start(404, "Please don't repeatedly request this endpoint");
+    let cache = temp_dir("fetch-error");
+    let client = || Ok(Client::new(&server.url, "secret"));
+
+    let err = fetch(&cache, 25, client).err().expect("expected an error");
+    assert!(err.to_string().contains("404"), "{err}");
+    assert!(!cached_input_path(&cache, 25).exists());
+}
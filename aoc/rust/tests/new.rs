@@ -0,0 +1,66 @@
+mod common;
+
+use std::path::Path;
+
+use aoc::{scaffold::new_day, workspace_dir};
+use common::temp_dir;
+
+/// Copies the files `new_day` edits into a fresh directory.
+fn fixture_workspace() -> anyhow::Result<std::path::PathBuf> {
+    let dir = temp_dir("new");
+    for file in [
+        "Cargo.toml",
+        "aoc/rust/Cargo.toml",
+        "aoc/rust/src/registry.rs",
+    ] {
+        let to = dir.join(file);
+        std::fs::create_dir_all(to.parent().unwrap())?;
+        std::fs::copy(workspace_dir().join(file), to)?;
+    }
+    Ok(dir)
+}
+
+fn read(path: impl AsRef<Path>) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn creates_and_registers_day() -> anyhow::Result<()> {
+    let workspace = fixture_workspace()?;
+    let created = new_day(&workspace, 9)?;
+    assert_eq!(created.len(), 5);
+    assert!(created
+        .iter()
+        .all(|path| path.starts_with(workspace.join("day9"))));
+
+    assert!(read(workspace.join("day9/rust/Cargo.toml")).contains("name = \"day9_rust\""));
+    assert!(read(workspace.join("day9/rust/src/main.rs")).contains("day9_rust::Day9"));
+    let lib = read(workspace.join("day9/rust/src/lib.rs"));
+    assert!(lib.contains("impl Solution for Day9") && !lib.contains("todo!"));
+    assert_eq!(read(workspace.join("day9/examples/example.txt")), "");
+
+    assert!(read(workspace.join("Cargo.toml"))
+        .contains("day8_rust = { path = \"day8/rust\" }\nday9_rust = { path = \"day9/rust\" }\n"));
+    assert!(read(workspace.join("aoc/rust/Cargo.toml"))
+        .contains("day8_rust.workspace = true\nday9_rust.workspace = true\n"));
+
+    let registry = read(workspace.join("aoc/rust/src/registry.rs"));
+    assert!(registry.contains("day9_rust::Day9::solve_timed(input, 1)"));
+    assert!(registry
+        .contains("day9_rust::Day9::solve_timed(input, 2),\n        stream: None,\n    },\n];\n"));
+    Ok(())
+}
+
+#[test]
+fn refuses_days_outside_advent() {
+    for day in [0, 26] {
+        let err = new_day(&workspace_dir(), day).unwrap_err();
+        assert!(err.to_string().contains("between 1 and 25"), "{err}");
+    }
+}
+
+#[test]
+fn refuses_existing_day() {
+    let err = new_day(&workspace_dir(), 1).unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{err}");
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use std::time::{Duration, SystemTime};
+
+use aoc::{
+    client::Client,
+    submit::{read_log, submit, Outcome},
+};
+use common::{temp_dir, StubServer};
+
+fn temp_log(name: &str) -> std::path::PathBuf {
+    temp_dir(name).join("submissions.toml")
+}
+
+#[test]
+fn records_correct_answer_and_stops() -> anyhow::Result<()> {
+    let server = StubServer::start(200, "<article><p>That's the right answer!</p></article>");
+    let log = temp_log("submit-correct");
+    let client = || Ok(Client::new(&server.url, "secret"));
+
+    let attempt = submit(&log, 3, 2, "48", SystemTime::now(), client)?;
+    assert_eq!(attempt.outcome, Outcome::Correct);
+    assert_eq!(attempt.retry_at, None);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].head.starts_with("POST /2024/day/3/answer "));
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=2&answer=48");
+    assert_eq!(read_log(&log)?, [attempt]);
+
+    let client = || -> anyhow::Result<Client> { panic!("solved part must not connect") };
+    let err = submit(&log, 3, 2, "49", SystemTime::now(), client).unwrap_err();
+    assert!(err.to_string().contains("already solved with 48"), "{err}");
+    Ok(())
+}
+
+#[test]
+fn refuses_rejected_answers_and_waits_out_cooldown() -> anyhow::Result<()> {
+    let server = StubServer::start(
+        200,
+        "<article><p>That's not the right answer; your answer is too high.  \
+         Please wait one minute before trying again.</p></article>",
+    );
+    let log = temp_log("submit-wrong");
+    let client = || Ok(Client::new(&server.url, "secret"));
+    let now = SystemTime::now();
+
+    let attempt = submit(&log, 1, 1, "500", now, client)?;
+    assert_eq!(attempt.outcome, Outcome::TooHigh);
+    assert_eq!(attempt.retry_at, Some(attempt.submitted_at + 60));
+
+    for answer in ["500", "600"] {
+        let later = now + Duration::from_secs(120);
+        let err = submit(&log, 1, 1, answer, later, client).unwrap_err();
+        assert!(err.to_string().contains("too high"), "{err}");
+    }
+    let soon = now + Duration::from_secs(30);
+    let err = submit(&log, 1, 1, "400", soon, client).unwrap_err();
+    assert!(err.to_string().contains("cooling down"), "{err}");
+    assert_eq!(server.requests().len(), 1);
+
+    let later = now + Duration::from_secs(61);
+    submit(&log, 1, 1, "400", later, client)?;
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(read_log(&log)?.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn unrecognized_response_is_not_logged() {
+    let server = StubServer::start(200, "<p>Something else entirely</p>");
+    let log = temp_log("submit-unknown");
+    let client = || Ok(Client::new(&server.url, "secret"));
+
+    assert!(submit(&log, 1, 1, "1", SystemTime::now(), client).is_err());
+    assert!(!log.exists());
+}
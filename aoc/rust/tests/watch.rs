@@ -0,0 +1,20 @@
+mod common;
+
+use aoc::watch::mtimes;
+use common::temp_dir;
+
+#[test]
+fn lists_files_under_watched_paths() {
+    let dir = temp_dir("mtimes");
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+    std::fs::write(dir.join("input.txt"), "").unwrap();
+
+    let paths = [dir.join("src"), dir.join("input.txt"), dir.join("missing")];
+    let times = mtimes(&paths);
+    assert_eq!(
+        times.keys().collect::<Vec<_>>(),
+        [&dir.join("input.txt"), &dir.join("src/lib.rs")]
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,132 @@
+//! A global allocator that counts allocations, for reporting the memory use of each phase.
+//!
+//! A binary opts in with `#[global_allocator] static ALLOC: Counting = Counting;`. Counts are
+//! kept per thread, so that parts solved in parallel don't see each other's allocations.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Wraps the system allocator, counting on the calling thread.
+pub struct Counting;
+
+/// Whether [`Counting`] is the global allocator, which it is as soon as anything allocates.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters { count: 0, bytes: 0, live: 0, peak: 0 })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    // Fails only while the thread is being torn down, when there is nothing left to measure.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        if allocated > 0 {
+            c.count += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made during one phase on one thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocs {
+    /// Number of allocations, counting each reallocation as one.
+    pub count: u64,
+    /// Total bytes requested, whether or not they were freed again.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live when the phase started.
+    pub peak: u64,
+}
+
+/// Runs `f`, also returning its allocations if [`Counting`] is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    let before = COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        c.peak = c.live;
+        counters.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+    let allocs = INSTALLED.load(Ordering::Relaxed).then(|| Allocs {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live) as u64,
+    });
+    (result, allocs)
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+#[test]
+fn test_measure() {
+    let (sum, allocs) = measure(|| {
+        let big = vec![1u8; 1000];
+        let small = Box::new([1u8; 10]);
+        drop(big);
+        let mut grown = Vec::<u8>::with_capacity(100);
+        grown.reserve_exact(200);
+        small.len() + grown.capacity()
+    });
+    assert_eq!(sum, 210);
+    assert_eq!(
+        allocs,
+        Some(Allocs {
+            count: 4,
+            bytes: 1000 + 10 + 100 + 200,
+            peak: 1010
+        })
+    );
+}
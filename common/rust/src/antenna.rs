@@ -0,0 +1,57 @@
+//! Antenna frequencies scattered over a rectangular map.
+
+use std::{fmt::Display, str::FromStr};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{error::ParseError, grid::Grid, point::Point};
+
+const EMPTY: char = '.';
+
+#[derive(Debug)]
+pub struct AntennaMap {
+    pub grid: Grid<char>,
+    /// Positions of the antennas of each frequency.
+    pub antennas: IndexMap<char, IndexSet<Point>>,
+}
+impl FromStr for AntennaMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, |ch| {
+            if ch == EMPTY || ch.is_ascii_alphanumeric() {
+                Ok(ch)
+            } else {
+                Err("expected `.`, a letter or a digit")
+            }
+        })?;
+
+        let mut antennas = IndexMap::<_, IndexSet<_>>::new();
+        for (position, &ch) in grid.iter().filter(|(_, &ch)| ch != EMPTY) {
+            antennas.entry(ch).or_default().insert(position);
+        }
+        Ok(Self { grid, antennas })
+    }
+}
+impl Display for AntennaMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+#[test]
+fn round_trip() -> anyhow::Result<()> {
+    let text = "..a.\n.0..\n...a\n";
+    let map = AntennaMap::from_str(text)?;
+    assert_eq!(map.antennas.len(), 2);
+    assert_eq!(text, map.to_string());
+    Ok(())
+}
+
+#[test]
+fn parse_errors() {
+    let err = AntennaMap::from_str("..a.\n.0#.\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 3, "#"));
+    let err = AntennaMap::from_str("..a.\n.0.\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, ".0."));
+}
@@ -0,0 +1,329 @@
+//! Dense sets of grid cells and of guard states, one bit each, for when hash sets are too slow.
+//!
+//! [`Set`] covers both these and the sparse hash sets, so that code can be switched between them.
+
+use std::{collections::HashSet, hash::Hash};
+
+use indexmap::IndexSet;
+
+use crate::{direction::Direction, point::Point};
+
+/// A set of indices below a fixed capacity.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+    len: usize,
+}
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(64)],
+            capacity,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.capacity && self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns whether `index` was new. Panics if it is not below the capacity.
+    pub fn insert(&mut self, index: usize) -> bool {
+        assert!(index < self.capacity, "{index} is out of bounds");
+        let word = &mut self.words[index / 64];
+        let bit = 1 << (index % 64);
+        let new = *word & bit == 0;
+        *word |= bit;
+        self.len += new as usize;
+        new
+    }
+
+    /// Returns whether `index` was there.
+    pub fn remove(&mut self, index: usize) -> bool {
+        if !self.contains(index) {
+            return false;
+        }
+        self.words[index / 64] &= !(1 << (index % 64));
+        self.len -= 1;
+        true
+    }
+
+    /// Empties the set, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// The indices in the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+/// A set of the cells of a grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CellSet {
+    height: usize,
+    width: usize,
+    bits: BitSet,
+}
+impl CellSet {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            bits: BitSet::new(height * width),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Whether `position` is in the set, which it never is off the grid.
+    pub fn contains(&self, position: Point) -> bool {
+        position
+            .to_index(self.height, self.width)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    /// Returns whether `position` was new. Panics off the grid.
+    pub fn insert(&mut self, position: Point) -> bool {
+        let index = position
+            .to_index(self.height, self.width)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"));
+        self.bits.insert(index)
+    }
+
+    pub fn remove(&mut self, position: Point) -> bool {
+        position
+            .to_index(self.height, self.width)
+            .is_some_and(|index| self.bits.remove(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// The positions in the set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.bits
+            .iter()
+            .map(|index| Point::from_index(index, self.width))
+    }
+}
+
+/// A set of positions on a grid, each with a direction, such as where a guard has been facing
+/// which way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StateSet {
+    height: usize,
+    width: usize,
+    bits: BitSet,
+}
+impl StateSet {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            bits: BitSet::new(height * width * Direction::ALL.len()),
+        }
+    }
+
+    fn index(&self, position: Point, direction: Direction) -> Option<usize> {
+        let cell = position.to_index(self.height, self.width)?;
+        Some(cell * Direction::ALL.len() + direction as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn contains(&self, position: Point, direction: Direction) -> bool {
+        self.index(position, direction)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    /// Returns whether the state was new. Panics off the grid.
+    pub fn insert(&mut self, position: Point, direction: Direction) -> bool {
+        let index = self
+            .index(position, direction)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"));
+        self.bits.insert(index)
+    }
+
+    pub fn remove(&mut self, position: Point, direction: Direction) -> bool {
+        self.index(position, direction)
+            .is_some_and(|index| self.bits.remove(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// The states in the set, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, Direction)> + '_ {
+        self.bits.iter().map(|index| {
+            let cell = index / Direction::ALL.len();
+            let direction = Direction::ALL[index % Direction::ALL.len()];
+            (Point::from_index(cell, self.width), direction)
+        })
+    }
+}
+
+/// A set of `T`s found on a grid, either sparse or dense.
+pub trait Set<T>: Clone {
+    /// An empty set for a grid of `height` by `width`, which only dense sets need to know.
+    fn with_size(height: usize, width: usize) -> Self;
+    /// Returns whether `item` was new.
+    fn insert(&mut self, item: T) -> bool;
+    fn contains(&self, item: T) -> bool;
+    fn clear(&mut self);
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+impl<T: Clone + Eq + Hash> Set<T> for HashSet<T> {
+    fn with_size(_: usize, _: usize) -> Self {
+        Self::new()
+    }
+    fn insert(&mut self, item: T) -> bool {
+        self.insert(item)
+    }
+    fn contains(&self, item: T) -> bool {
+        self.contains(&item)
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+/// Also remembers the order the items were added in.
+impl<T: Clone + Eq + Hash> Set<T> for IndexSet<T> {
+    fn with_size(_: usize, _: usize) -> Self {
+        Self::new()
+    }
+    fn insert(&mut self, item: T) -> bool {
+        self.insert(item)
+    }
+    fn contains(&self, item: T) -> bool {
+        self.contains(&item)
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl Set<Point> for CellSet {
+    fn with_size(height: usize, width: usize) -> Self {
+        Self::new(height, width)
+    }
+    fn insert(&mut self, position: Point) -> bool {
+        self.insert(position)
+    }
+    fn contains(&self, position: Point) -> bool {
+        self.contains(position)
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[test]
+fn test_bitset() {
+    let mut bits = BitSet::new(130);
+    assert!(bits.insert(0) && bits.insert(64) && bits.insert(129));
+    assert!(!bits.insert(64));
+    assert_eq!(bits.len(), 3);
+    assert!(bits.contains(129) && !bits.contains(128) && !bits.contains(1000));
+    assert_eq!(bits.iter().collect::<Vec<_>>(), [0, 64, 129]);
+
+    let copy = bits.clone();
+    assert!(bits.remove(64) && !bits.remove(64));
+    assert_eq!(bits.len(), 2);
+    bits.clear();
+    assert!(bits.is_empty() && !bits.contains(0));
+    assert_eq!((bits.capacity(), copy.len()), (130, 3));
+}
+
+#[test]
+#[should_panic = "out of bounds"]
+fn test_bitset_capacity() {
+    BitSet::new(10).insert(10);
+}
+
+#[test]
+fn test_cells_and_states() {
+    let mut cells = CellSet::new(2, 3);
+    assert!(cells.insert(Point::new(1, 2)) && cells.insert(Point::new(0, 1)));
+    assert!(!cells.insert(Point::new(1, 2)));
+    assert!(!cells.contains(Point::new(-1, 2)) && !cells.remove(Point::new(2, 0)));
+    assert_eq!(
+        cells.iter().collect::<Vec<_>>(),
+        [Point::new(0, 1), Point::new(1, 2)]
+    );
+
+    let mut states = StateSet::new(2, 3);
+    let corner = Point::new(1, 2);
+    assert!(states.insert(corner, Direction::Up) && states.insert(corner, Direction::Left));
+    assert!(!states.insert(corner, Direction::Up));
+    assert!(!states.contains(corner, Direction::Down));
+    assert_eq!(
+        states.iter().collect::<Vec<_>>(),
+        [(corner, Direction::Up), (corner, Direction::Left)]
+    );
+    assert!(states.remove(corner, Direction::Up));
+    states.clear();
+    assert!(states.is_empty());
+}
+
+#[test]
+fn test_sparse_and_dense_agree() {
+    fn fill<S: Set<Point>>() -> Vec<bool> {
+        let mut set = S::with_size(4, 4);
+        let points = [(0, 0), (3, 3), (0, 0), (2, 1), (3, 3)].map(Point::from);
+        let inserted = points.map(|point| set.insert(point));
+        let mut results = inserted.to_vec();
+        results.push(set.contains(Point::new(2, 1)));
+        results.push(set.len() == 3);
+        set.clear();
+        results.push(set.is_empty());
+        results
+    }
+    assert_eq!(fill::<CellSet>(), fill::<HashSet<Point>>());
+    assert_eq!(fill::<CellSet>(), fill::<IndexSet<Point>>());
+}
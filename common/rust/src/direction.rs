@@ -0,0 +1,270 @@
+//! Compass directions on a grid, four or eight of them.
+//!
+//! Both kinds list their directions clockwise from up, so that turning is counting along them.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::point::Vector;
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Every direction, clockwise from up.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    /// Parses an arrow (`^>v<`), a compass letter (`NESW`) or a letter of `UDLR`.
+    pub fn new(ch: char) -> Option<Self> {
+        match ch {
+            '^' | 'N' | 'U' => Some(Self::Up),
+            '>' | 'E' | 'R' => Some(Self::Right),
+            'v' | 'S' | 'D' => Some(Self::Down),
+            '<' | 'W' | 'L' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
+    /// Turns `steps` quarter turns clockwise, or anticlockwise if negative.
+    #[must_use]
+    pub fn rotate(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(4) as usize]
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    #[must_use]
+    pub fn turn_around(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// One step in this direction.
+    pub fn vector(self) -> Vector {
+        Direction8::from(self).vector()
+    }
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Every direction, clockwise from up.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    /// Turns `steps` eighth turns clockwise, or anticlockwise if negative.
+    #[must_use]
+    pub fn rotate(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    /// An eighth turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// An eighth turn anticlockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    #[must_use]
+    pub fn turn_around(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// One step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(-1, 0),
+            Self::UpRight => Vector::new(-1, 1),
+            Self::Right => Vector::new(0, 1),
+            Self::DownRight => Vector::new(1, 1),
+            Self::Down => Vector::new(1, 0),
+            Self::DownLeft => Vector::new(1, -1),
+            Self::Left => Vector::new(0, -1),
+            Self::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+impl TryFrom<Direction8> for Direction {
+    type Error = ();
+
+    /// Fails for the diagonal directions.
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        if direction.is_diagonal() {
+            Err(())
+        } else {
+            Ok(Self::ALL[direction as usize / 2])
+        }
+    }
+}
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+impl From<Direction8> for Vector {
+    fn from(direction: Direction8) -> Self {
+        direction.vector()
+    }
+}
+
+/// One character, as accepted by [`Direction::new`].
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Self::new(ch).ok_or(()),
+            _ => Err(()),
+        }
+    }
+}
+/// An orthogonal direction as accepted by [`Direction::new`], or a diagonal one as two
+/// letters, vertical first: `NE`, `SE`, `SW` and `NW`, or `UR`, `DR`, `DL` and `UL`.
+impl FromStr for Direction8 {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(direction) = Direction::from_str(s) {
+            return Ok(direction.into());
+        }
+        match s {
+            "NE" | "UR" => Ok(Self::UpRight),
+            "SE" | "DR" => Ok(Self::DownRight),
+            "SW" | "DL" => Ok(Self::DownLeft),
+            "NW" | "UL" => Ok(Self::UpLeft),
+            _ => Err(()),
+        }
+    }
+}
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[test]
+fn test_turns() {
+    use Direction::*;
+
+    assert_eq!(Up.turn_right(), Right);
+    assert_eq!(Up.turn_left(), Left);
+    assert_eq!(Left.turn_around(), Right);
+    assert_eq!(Down.rotate(7), Right);
+    assert_eq!(Down.rotate(-6), Up);
+    assert!(Direction::all().all(|d| d.turn_right().turn_left() == d));
+
+    assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+    assert_eq!(Direction8::DownLeft.turn_around(), Direction8::UpRight);
+    assert_eq!(Direction8::Left.rotate(-10), Direction8::Down);
+}
+
+#[test]
+fn test_vectors() {
+    let sum = Direction8::all().fold(Vector::default(), |sum, d| sum + d.vector());
+    assert_eq!(sum, Vector::default());
+    assert!(Direction8::all().all(|d| d.vector().chebyshev() == 1));
+    assert!(Direction8::all().all(|d| d.vector().manhattan() == 1 + d.is_diagonal() as u32));
+    for direction in Direction::all() {
+        assert_eq!(
+            Vector::from(direction),
+            Direction8::from(direction).vector()
+        );
+        assert_eq!(direction.turn_around().vector(), -direction.vector());
+        assert_eq!(
+            Direction::try_from(Direction8::from(direction)),
+            Ok(direction)
+        );
+    }
+    assert_eq!(Direction::try_from(Direction8::UpLeft), Err(()));
+}
+
+#[test]
+fn test_parse() {
+    for (text, direction) in [
+        ("^NU", Direction::Up),
+        (">ER", Direction::Right),
+        ("vSD", Direction::Down),
+        ("<WL", Direction::Left),
+    ] {
+        for ch in text.chars() {
+            assert_eq!(Direction::new(ch), Some(direction));
+        }
+    }
+    assert_eq!(Direction::from_str("^"), Ok(Direction::Up));
+    assert_eq!(Direction::from_str("^^"), Err(()));
+    assert_eq!(Direction::from_str(""), Err(()));
+    assert_eq!(Direction::new('x'), None);
+    assert_eq!(Direction::Left.to_string(), "<");
+
+    assert_eq!(Direction8::from_str("W"), Ok(Direction8::Left));
+    assert_eq!(Direction8::from_str("SE"), Ok(Direction8::DownRight));
+    assert_eq!(Direction8::from_str("UL"), Ok(Direction8::UpLeft));
+    assert_eq!(Direction8::from_str("EN"), Err(()));
+}
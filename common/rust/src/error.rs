@@ -0,0 +1,141 @@
+//! Parse errors that point at the offending part of the input.
+
+use std::{fmt::Display, str::FromStr};
+
+/// A parse failure at a 1-based line and column of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The part of the input the error is about, cut off at the end of its line.
+    pub snippet: String,
+    pub message: String,
+    /// The whole line containing the snippet, for the caret diagnostic.
+    source_line: String,
+}
+impl ParseError {
+    /// An error about `part`, which must be a subslice of `input`.
+    pub fn at(input: &str, part: &str, message: impl Display) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + part.len() <= input.len())
+            .expect("part should be a subslice of input");
+        Self::at_offset(input, offset, part.len(), message)
+    }
+
+    /// An error about something missing from the end of `input`.
+    pub fn at_end(input: &str, message: impl Display) -> Self {
+        Self::at_offset(input, input.len(), 0, message)
+    }
+
+    fn at_offset(input: &str, offset: usize, len: usize, message: impl Display) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+        let snippet_end = (offset + len)
+            .min(line_start + source_line.len())
+            .max(offset);
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[offset..snippet_end].to_owned(),
+            message: message.to_string(),
+            source_line: source_line.to_owned(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for errors found in one line of a larger input.
+    #[must_use]
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.snippet.chars().count().max(1));
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{number} | {}", self.source_line)?;
+        write!(f, "{gutter} | {indent}{carets}")
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Parses `part` of `input` with [`FromStr`], reporting failures at its position.
+pub fn parse_at<T>(input: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    part.parse().map_err(|err| ParseError::at(input, part, err))
+}
+
+/// Checks that the input is text, reporting the first invalid byte.
+pub fn utf8(input: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(input).map_err(|err| {
+        let valid = err.valid_up_to();
+        // The prefix is valid by definition.
+        let text = std::str::from_utf8(&input[..valid]).unwrap();
+        ParseError::at_offset(text, valid, 0, "invalid UTF-8")
+    })
+}
+
+#[test]
+fn test_at() {
+    let input = "1 2\n3 x 5\n";
+    let err = ParseError::at(input, &input[6..7], "not a number");
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.snippet, "x");
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: not a number\n2 | 3 x 5\n  |   ^"
+    );
+}
+
+#[test]
+fn test_at_end() {
+    let input = "1 2\n";
+    let err = ParseError::at_end(input, "unexpected end of input");
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.snippet, "");
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: unexpected end of input\n2 | \n  | ^"
+    );
+}
+
+#[test]
+fn test_multiline_snippet() {
+    let input = "ab\ncd";
+    let err = ParseError::at(input, &input[1..], "bad").below(9);
+    assert_eq!((err.line, err.column), (10, 2));
+    assert_eq!(err.snippet, "b");
+    assert_eq!(err.to_string(), "line 10, column 2: bad\n10 | ab\n   |  ^");
+}
+
+#[test]
+fn test_parse_at() {
+    let input = "12 3a";
+    assert_eq!(parse_at::<i32>(input, &input[..2]), Ok(12));
+    let err = parse_at::<i32>(input, &input[3..]).unwrap_err();
+    assert_eq!((err.line, err.column), (1, 4));
+    assert_eq!(err.snippet, "3a");
+    assert_eq!(err.message, "invalid digit found in string");
+}
+
+#[test]
+fn test_utf8() {
+    assert_eq!(utf8(b"ok"), Ok("ok"));
+    let err = utf8(b"ab\nc\xffd").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.message, "invalid UTF-8");
+}
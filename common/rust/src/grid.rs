@@ -0,0 +1,250 @@
+//! A rectangle of cells, as most grid puzzles are given.
+//!
+//! Lookups take signed [`Point`]s, so that stepping off the edge of the grid is just a lookup
+//! that finds nothing.
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{direction::Direction8, error::ParseError, point::Point};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    /// Row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`. Panics if `width` is 0, as rows need a cell to
+    /// tell them apart, just as a parsed grid has no empty rows.
+    pub fn new(height: usize, width: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "a grid needs at least one column");
+        Self {
+            height,
+            width,
+            cells: vec![fill; height * width],
+        }
+    }
+
+    /// Parses one row per line, turning each character into a cell with `cell`, which
+    /// explains what it expected when the character is not a valid cell.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_rows(input, input.lines(), cell)
+    }
+
+    /// Like [`Grid::parse`], for rows that are subslices of `input` other than its lines,
+    /// such as lines with the indentation trimmed.
+    pub fn parse_rows<'a>(
+        input: &'a str,
+        rows: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for row in rows {
+            let len = row.chars().count();
+            if len == 0 {
+                return Err(ParseError::at(input, row, "empty row"));
+            }
+            let width = *width.get_or_insert(len);
+            if len != width {
+                let message = format!("expected a row of {width} cells");
+                return Err(ParseError::at(input, row, message));
+            }
+            for (i, ch) in row.char_indices() {
+                let parsed = cell(ch).map_err(|message| {
+                    ParseError::at(input, &row[i..i + ch.len_utf8()], message)
+                })?;
+                cells.push(parsed);
+            }
+            height += 1;
+        }
+        let width = width.ok_or_else(|| ParseError::at_end(input, "empty grid"))?;
+        Ok(Self {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn offset(&self, position: Point) -> Option<usize> {
+        position.to_index(self.height, self.width)
+    }
+
+    /// The cell at `position`, or `None` off the grid.
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.offset(position).is_some()
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |offset| Point::from_index(offset, width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.cells[column..].iter().step_by(self.width))
+    }
+
+    /// The neighbours of `position` that are on the grid, orthogonal ones first, and
+    /// diagonal ones too if `diagonal`.
+    pub fn neighbours(&self, position: Point, diagonal: bool) -> impl Iterator<Item = (Point, &T)> {
+        let orthogonal = Direction8::all().filter(|d| !d.is_diagonal());
+        let diagonals = Direction8::all().filter(move |d| diagonal && d.is_diagonal());
+        orthogonal.chain(diagonals).filter_map(move |direction| {
+            let position = position + direction.vector();
+            Some((position, self.get(position)?))
+        })
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+/// Panics off the grid, like indexing a slice out of bounds.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"))
+    }
+}
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is off the grid"))
+    }
+}
+/// One row per line, as the grid was parsed.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+/// A list of rows, like the `Vec<Vec<T>>` it replaces.
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+#[cfg(test)]
+fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |ch| ch.to_digit(10).ok_or("expected a digit"))
+}
+
+#[test]
+fn test_parse_and_display() {
+    let grid = digits("123\n456\n").unwrap();
+    assert_eq!((grid.height(), grid.width()), (2, 3));
+    assert_eq!(grid.to_string(), "123\n456\n");
+    assert_eq!(format!("{grid:?}"), "[[1, 2, 3], [4, 5, 6]]");
+
+    let err = digits("123\n4x6\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "x"));
+    assert_eq!(err.message, "expected a digit");
+    let err = digits("123\n45\n").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, "45"));
+    assert!(digits("").is_err());
+    assert!(digits("\n1").is_err());
+}
+
+#[test]
+fn test_new() {
+    let grid = Grid::new(2, 3, 0);
+    assert_eq!(grid.to_string(), "000\n000\n");
+    assert_eq!(Grid::new(0, 3, 0).to_string(), "");
+}
+
+#[test]
+#[should_panic = "at least one column"]
+fn test_new_without_columns() {
+    Grid::new(2, 0, 0);
+}
+
+#[test]
+fn test_get() {
+    let mut grid = digits("12\n34").unwrap();
+    assert_eq!(grid.get(Point::new(1, 0)), Some(&3));
+    assert_eq!(grid.get(Point::new(-1, 0)), None);
+    assert_eq!(grid.get(Point::new(0, 2)), None);
+    assert_eq!(grid.get(Point::new(2, 0)), None);
+    *grid.get_mut(Point::new(0, 1)).unwrap() = 9;
+    grid[Point::new(1, 1)] += 1;
+    assert_eq!(grid.to_string(), "19\n35\n");
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = digits("12\n34\n56").unwrap();
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2], [3, 4], [5, 6]]);
+    let columns: Vec<Vec<_>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, [[1, 3, 5], [2, 4, 6]]);
+    assert_eq!(
+        grid.iter()
+            .map(|(pos, _)| (pos.row, pos.column))
+            .collect::<Vec<_>>(),
+        [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]
+    );
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = digits("123\n456\n789").unwrap();
+    let around = |pos: (i32, i32), diagonal| {
+        grid.neighbours(Point::from(pos), diagonal)
+            .map(|(_, &cell)| cell)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(around((1, 1), false), [2, 6, 8, 4]);
+    assert_eq!(around((1, 1), true), [2, 6, 8, 4, 3, 9, 7, 1]);
+    assert_eq!(around((0, 0), true), [2, 4, 5]);
+}
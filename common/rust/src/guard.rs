@@ -0,0 +1,318 @@
+//! The lab guard patrol: a map of obstacles and a guard walking through it.
+
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use crate::{
+    bitset::{CellSet, Set, StateSet},
+    direction::Direction,
+    error::ParseError,
+    grid::Grid,
+    point::Point,
+};
+
+/// Every cell of the map, ignoring whitespace around the rows.
+fn cells(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_rows(input, input.lines().map(str::trim), |ch| {
+        if ['.', '#', '^', 'v', '>', '<'].contains(&ch) {
+            Ok(ch)
+        } else {
+            Err("expected one of `.#^v><`")
+        }
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    /// Whether each cell has an obstacle.
+    pub obstacles: Grid<bool>,
+}
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let obstacles = cells(input)?.map(|&ch| ch == '#');
+        Ok(Self { obstacles })
+    }
+
+    /// An empty set of cells or guard states, sized for this map if it is dense.
+    pub fn new_set<T, S: Set<T>>(&self) -> S {
+        S::with_size(self.obstacles.height(), self.obstacles.width())
+    }
+
+    pub fn on_map(&self, position: Point) -> bool {
+        self.obstacles.contains(position)
+    }
+
+    pub fn is_obstacle(&self, position: Point) -> bool {
+        self.obstacles.get(position) == Some(&true)
+    }
+
+    /// Places or removes an obstacle at a position on the map.
+    pub fn set_obstacle(&mut self, position: Point, obstacle: bool) {
+        *self
+            .obstacles
+            .get_mut(position)
+            .expect("position on the map") = obstacle;
+    }
+
+    /// The cells next to `position` without an obstacle.
+    pub fn open_neighbours(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.obstacles
+            .neighbours(position, false)
+            .filter(|(_, &obstacle)| !obstacle)
+            .map(|(position, _)| position)
+    }
+
+    /// What a guard free to choose can do next, with the cost of each: step forward for 1, or
+    /// turn either way for `turn_cost`.
+    pub fn moves(&self, guard: Guard, turn_cost: u64) -> impl Iterator<Item = (Guard, u64)> {
+        let forward = guard.move_forward();
+        let step = (self.on_map(forward.position) && !self.is_obstacle(forward.position))
+            .then_some((forward, 1));
+        let turns = [guard.turn_left(), guard.turn_right()].map(|guard| (guard, turn_cost));
+        step.into_iter().chain(turns)
+    }
+
+    pub fn next(&self, guard: Guard) -> Option<Guard> {
+        let next_cell = guard.next_cell();
+        if !self.on_map(next_cell) {
+            return None;
+        }
+        if self.is_obstacle(next_cell) {
+            Some(guard.turn_right())
+        } else {
+            Some(guard.move_forward())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guard {
+    pub position: Point,
+    pub direction: Direction,
+}
+impl Guard {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (position, direction) = cells(input)?
+            .iter()
+            .find_map(|(position, &ch)| Direction::new(ch).map(|dir| (position, dir)))
+            .ok_or_else(|| ParseError::at_end(input, "no guard on the map"))?;
+
+        Ok(Self {
+            position,
+            direction,
+        })
+    }
+
+    pub fn next_cell(self) -> Point {
+        self.position + self.direction.vector()
+    }
+
+    #[must_use]
+    pub fn move_forward(self) -> Self {
+        Self {
+            position: self.next_cell(),
+            direction: self.direction,
+        }
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self {
+            position: self.position,
+            direction: self.direction.turn_right(),
+        }
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self {
+            position: self.position,
+            direction: self.direction.turn_left(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    pub map: Map,
+    pub guard: Guard,
+}
+impl Board {
+    /// Walks the guard until they leave the map or are stuck in a loop, passing every state to
+    /// `visit` once, and returns `true` if they looped.
+    ///
+    /// `seen` is cleared first, so that one set can be reused for many walks.
+    pub fn walk<S: Set<Guard>>(&self, seen: &mut S, mut visit: impl FnMut(Guard)) -> bool {
+        seen.clear();
+        let mut guard = self.guard;
+        loop {
+            if !seen.insert(guard) {
+                return true;
+            }
+            visit(guard);
+            match self.map.next(guard) {
+                Some(next) => guard = next,
+                None => return false,
+            }
+        }
+    }
+}
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            map: Map::new(s)?,
+            guard: Guard::new(s)?,
+        })
+    }
+}
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = self
+            .map
+            .obstacles
+            .map(|&obstacle| if obstacle { '#' } else { '.' });
+        if let Some(cell) = grid.get_mut(self.guard.position) {
+            *cell = self.guard.direction.arrow();
+        }
+        write!(f, "{grid}")
+    }
+}
+
+/// Where walks keep the cells and guard states they have seen, so that solutions can be
+/// benchmarked with either kind of set.
+pub trait Storage {
+    type Cells: Set<Point>;
+    type States: Set<Guard>;
+}
+
+/// Hash sets, which grow with the walk.
+pub struct Sparse;
+impl Storage for Sparse {
+    type Cells = HashSet<Point>;
+    type States = HashSet<Guard>;
+}
+
+/// Bitsets covering the whole map, which are quicker to look up and to clear.
+pub struct Dense;
+impl Storage for Dense {
+    type Cells = CellSet;
+    type States = StateSet;
+}
+
+impl Set<Guard> for StateSet {
+    fn with_size(height: usize, width: usize) -> Self {
+        Self::new(height, width)
+    }
+    fn insert(&mut self, guard: Guard) -> bool {
+        self.insert(guard.position, guard.direction)
+    }
+    fn contains(&self, guard: Guard) -> bool {
+        self.contains(guard.position, guard.direction)
+    }
+    fn clear(&mut self) {
+        self.clear();
+    }
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[test]
+fn make_step_up() {
+    let input = "....#.....
+                 .........#
+                 ..........
+                 ..#.......
+                 .......#..
+                 ..........
+                 .#..^.....
+                 ........#.
+                 #.........
+                 ......#...";
+    let mut board = Board::from_str(input).unwrap();
+    board.guard = board.map.next(board.guard).unwrap();
+    insta::assert_snapshot!(board);
+}
+
+#[test]
+fn make_step_right() {
+    let input = "....#.....
+                 .........#
+                 ..........
+                 ..#.......
+                 .......#..
+                 ..........
+                 .#..>.....
+                 ........#.
+                 #.........
+                 ......#...";
+    let mut board = Board::from_str(input).unwrap();
+    board.guard = board.map.next(board.guard).unwrap();
+    insta::assert_snapshot!(board);
+}
+
+#[test]
+fn turn_right() {
+    let input = "....#.....
+                 .........#
+                 ..........
+                 ..#.......
+                 .......#..
+                 ....#.....
+                 .#..^.....
+                 ........#.
+                 #.........
+                 ......#...";
+    let mut board = Board::from_str(input).unwrap();
+    board.guard = board.map.next(board.guard).unwrap();
+    insta::assert_snapshot!(board);
+}
+
+#[test]
+fn turn_down() {
+    let input = "....#.....
+                 .........#
+                 ..........
+                 ..#.......
+                 .......#..
+                 ..........
+                 .#..>#....
+                 ........#.
+                 #.........
+                 ......#...";
+    let mut board = Board::from_str(input).unwrap();
+    board.guard = board.map.next(board.guard).unwrap();
+    insta::assert_snapshot!(board);
+}
+
+#[test]
+fn parse_errors() {
+    let err = Board::from_str("..#\n.x^\n...").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "x"));
+    let err = Board::from_str("..#\n.^\n...").unwrap_err();
+    assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 1, ".^"));
+    let err = Board::from_str("..#\n...").unwrap_err();
+    assert_eq!(err.message, "no guard on the map");
+    assert!(Board::from_str("").is_err());
+}
+
+#[test]
+fn walk_with_either_storage() {
+    fn walk<S: Storage>(board: &Board) -> (usize, usize, bool) {
+        let mut cells: S::Cells = board.map.new_set();
+        let mut seen: S::States = board.map.new_set();
+        let looped = board.walk(&mut seen, |guard| {
+            cells.insert(guard.position);
+        });
+        (cells.len(), seen.len(), looped)
+    }
+
+    // Turns twice on the spot, then walks back down and off the map.
+    let leaves = Board::from_str("..#.\n...#\n..^.\n....").unwrap();
+    assert_eq!(walk::<Sparse>(&leaves), (3, 6, false));
+    assert_eq!(walk::<Dense>(&leaves), (3, 6, false));
+    let loops = Board::from_str(".#..\n.^.#\n#...\n..#.").unwrap();
+    assert_eq!(walk::<Sparse>(&loops), walk::<Dense>(&loops));
+    assert!(walk::<Dense>(&loops).2);
+}
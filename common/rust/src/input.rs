@@ -0,0 +1,243 @@
+//! Readers for the puzzle input formats.
+
+use std::io::BufRead;
+
+use crate::{
+    error::{parse_at, utf8, ParseError},
+    grid::Grid,
+};
+
+/// Reads the whole input as raw bytes.
+pub fn bytes(mut input: impl BufRead) -> anyhow::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    input.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads the whole input as a string.
+pub fn string(input: impl BufRead) -> anyhow::Result<String> {
+    String::from_utf8(bytes(input)?).map_err(|err| utf8(err.as_bytes()).unwrap_err().into())
+}
+
+/// Parses each line of the input separately.
+///
+/// Errors are reported relative to the line, and moved to its position in the input.
+pub fn lines<T>(
+    input: impl BufRead,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> anyhow::Result<Vec<T>> {
+    let s = string(input)?;
+    let parsed = s
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|err| err.below(index)))
+        .collect::<Result<_, _>>()?;
+    Ok(parsed)
+}
+
+/// Calls `f` on each line as it is read, so that the input never has to fit in memory.
+///
+/// Lines are split like [`str::lines`], and errors are moved to the line's position like
+/// [`lines`] does.
+pub fn for_each_line(
+    mut input: impl BufRead,
+    mut f: impl FnMut(&str) -> Result<(), ParseError>,
+) -> anyhow::Result<()> {
+    let mut line = vec![];
+    for index in 0.. {
+        line.clear();
+        if input.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        let text = utf8(&line).map_err(|err| err.below(index))?;
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(text).map_err(|err| err.below(index))?;
+    }
+    Ok(())
+}
+
+/// Two columns of numbers separated by three spaces.
+pub fn pairs(input: impl BufRead) -> anyhow::Result<Vec<(i32, i32)>> {
+    lines(input, |s| {
+        let (a, b) = s.split_once("   ").ok_or_else(|| {
+            ParseError::at(s, s, "expected two numbers separated by three spaces")
+        })?;
+        Ok((parse_at(s, a)?, parse_at(s, b)?))
+    })
+}
+
+/// Space-separated lists of numbers, one per line.
+pub fn reports(input: impl BufRead) -> anyhow::Result<Vec<Vec<i32>>> {
+    lines(input, report)
+}
+
+/// One line of [`reports`].
+pub fn report(line: &str) -> Result<Vec<i32>, ParseError> {
+    line.split(' ').map(|x| parse_at(line, x)).collect()
+}
+
+/// A rectangle of characters, one row per line.
+pub fn chars(input: impl BufRead) -> anyhow::Result<Grid<char>> {
+    Ok(Grid::parse(&string(input)?, Ok)?)
+}
+
+pub type Rules = Vec<(i32, i32)>;
+pub type Updates = Vec<Vec<i32>>;
+
+/// `a|b` rules, an empty line, then comma-separated updates.
+pub fn rules_and_updates(input: impl BufRead) -> anyhow::Result<(Rules, Updates)> {
+    let s = string(input)?;
+    let (first, second) = s.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(
+            &s,
+            "expected an empty line between the rules and the updates",
+        )
+    })?;
+
+    let first: Rules = first
+        .split('\n')
+        .map(|rule| {
+            let (a, b) = rule
+                .split_once('|')
+                .ok_or_else(|| ParseError::at(&s, rule, "expected a rule like `47|53`"))?;
+            Ok((parse_at(&s, a)?, parse_at(&s, b)?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let second: Updates = second
+        .lines()
+        .map(|update| update.split(',').map(|x| parse_at(&s, x)).collect())
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((first, second))
+}
+
+/// `test_value: a b c` equations, one per line.
+pub fn equations(input: impl BufRead) -> anyhow::Result<Vec<(u64, Vec<u64>)>> {
+    lines(input, equation)
+}
+
+/// One line of [`equations`].
+pub fn equation(line: &str) -> Result<(u64, Vec<u64>), ParseError> {
+    let (a, b) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(line, line, "expected `test_value: numbers`"))?;
+    Ok((
+        parse_at(line, a)?,
+        b.split(' ')
+            .map(|x| parse_at(line, x))
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+#[cfg(test)]
+fn error_at<T: std::fmt::Debug>(result: anyhow::Result<T>) -> (usize, usize, String) {
+    let err = result.unwrap_err().downcast::<ParseError>().unwrap();
+    (err.line, err.column, err.snippet)
+}
+
+#[test]
+fn test_string() {
+    assert_eq!(string(b"ok\n".as_slice()).unwrap(), "ok\n");
+    assert_eq!(
+        error_at(string(b"ok\n\xff".as_slice())),
+        (2, 1, "".to_owned())
+    );
+}
+
+#[test]
+fn test_for_each_line() {
+    let read = |input: &[u8]| {
+        let mut lines = vec![];
+        for_each_line(input, |line| {
+            lines.push(line.to_owned());
+            Ok(())
+        })
+        .map(|()| lines)
+    };
+    assert_eq!(read(b"a\r\n\nb").unwrap(), ["a", "", "b"]);
+    assert_eq!(read(b"a\n").unwrap(), ["a"]);
+    assert_eq!(error_at(read(b"a\nb\xff\n")), (2, 2, "".to_owned()));
+    assert_eq!(
+        error_at(for_each_line(b"1\n2 x\n".as_slice(), |line| report(line).map(drop))),
+        (2, 3, "x".to_owned())
+    );
+}
+
+#[test]
+fn test_pairs() {
+    assert_eq!(
+        pairs(b"3   4\n4   3\n".as_slice()).unwrap(),
+        [(3, 4), (4, 3)]
+    );
+    assert_eq!(
+        error_at(pairs(b"3   4\n3 4\n".as_slice())),
+        (2, 1, "3 4".to_owned())
+    );
+    assert_eq!(
+        error_at(pairs(b"3   x4".as_slice())),
+        (1, 5, "x4".to_owned())
+    );
+}
+
+#[test]
+fn test_reports() {
+    assert_eq!(
+        reports(b"7 6 4\n1 2\n".as_slice()).unwrap(),
+        [vec![7, 6, 4], vec![1, 2]]
+    );
+    assert_eq!(error_at(reports(b"7  6".as_slice())), (1, 3, "".to_owned()));
+}
+
+#[test]
+fn test_chars() {
+    assert_eq!(
+        chars(b"XM\nAS\n".as_slice()).unwrap().to_string(),
+        "XM\nAS\n"
+    );
+    assert_eq!(
+        error_at(chars(b"XM\nA\n".as_slice())),
+        (2, 1, "A".to_owned())
+    );
+    assert!(chars(b"".as_slice()).is_err());
+}
+
+#[test]
+fn test_rules_and_updates() {
+    assert_eq!(
+        rules_and_updates(b"47|53\n97|13\n\n75,47,61\n97,61\n".as_slice()).unwrap(),
+        (
+            vec![(47, 53), (97, 13)],
+            vec![vec![75, 47, 61], vec![97, 61]]
+        )
+    );
+    assert_eq!(
+        error_at(rules_and_updates(b"47|53\n".as_slice())),
+        (2, 1, "".to_owned())
+    );
+    assert_eq!(
+        error_at(rules_and_updates(b"47|53\n97-13\n\n75\n".as_slice())),
+        (2, 1, "97-13".to_owned())
+    );
+    assert_eq!(
+        error_at(rules_and_updates(b"47|53\n\n75,47\n97,,61\n".as_slice())),
+        (4, 4, "".to_owned())
+    );
+}
+
+#[test]
+fn test_equations() {
+    assert_eq!(
+        equations(b"190: 10 19\n83: 17 5\n".as_slice()).unwrap(),
+        [(190, vec![10, 19]), (83, vec![17, 5])]
+    );
+    assert_eq!(
+        error_at(equations(b"190 10 19".as_slice())),
+        (1, 1, "190 10 19".to_owned())
+    );
+    assert_eq!(
+        error_at(equations(b"190: 10 19\n83: 17 -5\n".as_slice())),
+        (2, 8, "-5".to_owned())
+    );
+}
@@ -0,0 +1,15 @@
+//! Code shared between the solutions.
+
+pub mod alloc;
+pub mod antenna;
+pub mod bitset;
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod guard;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod report;
+pub mod search;
+pub mod solution;
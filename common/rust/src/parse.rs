@@ -1,8 +1,8 @@
-use std::io::{BufReader, Read};
+//! Tiny combinators for scanning byte strings.
 
 use itertools::Itertools;
 
-fn parse_lit<'a>(input: &'a [u8], literal: &[u8]) -> Option<&'a [u8]> {
+pub fn parse_lit<'a>(input: &'a [u8], literal: &[u8]) -> Option<&'a [u8]> {
     input.starts_with(literal).then(|| &input[literal.len()..])
 }
 
@@ -15,7 +15,7 @@ fn test_parse_lit() {
     assert_eq!(parse_lit(b"xy", b"xz"), None);
 }
 
-fn parse_num(input: &[u8]) -> Option<(i32, &[u8])> {
+pub fn parse_num(input: &[u8]) -> Option<(i32, &[u8])> {
     let first_non_number = input
         .iter()
         .find_position(|n| !n.is_ascii_digit())
@@ -35,11 +35,24 @@ fn test_parse_num() {
     assert_eq!(parse_num(b"1a"), Some((1, b"a".as_slice())));
 }
 
-fn parse_mul(input: &[u8]) -> Option<((i32, i32), &[u8])> {
+/// A number of at most `digits` digits.
+pub fn parse_short_num(input: &[u8], digits: usize) -> Option<(i32, &[u8])> {
+    let (number, rest) = parse_num(input)?;
+    (input.len() - rest.len() <= digits).then_some((number, rest))
+}
+
+#[test]
+fn test_parse_short_num() {
+    assert_eq!(parse_short_num(b"123,", 3), Some((123, b",".as_slice())));
+    assert_eq!(parse_short_num(b"1234,", 3), None);
+}
+
+/// `mul(X,Y)` where the numbers have 1-3 digits each.
+pub fn parse_mul(input: &[u8]) -> Option<((i32, i32), &[u8])> {
     let input = parse_lit(input, b"mul(")?;
-    let (n1, input) = parse_num(input)?;
+    let (n1, input) = parse_short_num(input, 3)?;
     let input = parse_lit(input, b",")?;
-    let (n2, input) = parse_num(input)?;
+    let (n2, input) = parse_short_num(input, 3)?;
     let input = parse_lit(input, b")")?;
     Some(((n1, n2), input))
 }
@@ -50,31 +63,5 @@ fn test_parse_mul() {
     assert_eq!(parse_mul(b"mul(1,2)"), Some(((1, 2), b"".as_slice())));
     assert_eq!(parse_mul(b"mul(1,2)x"), Some(((1, 2), b"x".as_slice())));
     assert_eq!(parse_mul(b"xmul(1,2)"), None);
-}
-
-fn solution(mut input: &[u8]) -> i32 {
-    let mut sum = 0;
-    while !input.is_empty() {
-        if let Some(((a, b), new_input)) = parse_mul(input) {
-            sum += a * b;
-            input = new_input;
-        } else {
-            input = &input[1..];
-        }
-    }
-    sum
-}
-
-fn main() -> anyhow::Result<()> {
-    let mut bytes = vec![];
-    BufReader::new(std::io::stdin()).read_to_end(&mut bytes)?;
-    let answer = solution(&bytes);
-    println!("{answer}");
-    Ok(())
-}
-
-#[test]
-fn solve_example() {
-    let bytes = include_bytes!("../../example.txt");
-    assert_eq!(solution(bytes), 161);
+    assert_eq!(parse_mul(b"mul(1,2345)"), None);
 }
@@ -0,0 +1,170 @@
+//! Positions on a grid and the steps between them.
+//!
+//! Both are signed, so that stepping off the top or left edge of a grid gives a position
+//! that simply isn't on it.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position, as a row and a column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub column: i32,
+}
+
+/// The difference between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub row: i32,
+    pub column: i32,
+}
+
+impl Point {
+    pub const fn new(row: i32, column: i32) -> Self {
+        Self { row, column }
+    }
+
+    /// The position of the cell at `index` of a grid stored row by row.
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Self::new((index / width) as i32, (index % width) as i32)
+    }
+
+    /// The index of this position in a grid stored row by row, or `None` off the grid.
+    pub fn to_index(self, height: usize, width: usize) -> Option<usize> {
+        let row = usize::try_from(self.row).ok().filter(|&row| row < height)?;
+        let column = usize::try_from(self.column)
+            .ok()
+            .filter(|&column| column < width)?;
+        Some(row * width + column)
+    }
+
+    /// Steps along rows plus steps along columns.
+    pub fn manhattan(self, other: Self) -> u32 {
+        (self - other).manhattan()
+    }
+
+    /// Steps when diagonal steps are allowed too.
+    pub fn chebyshev(self, other: Self) -> u32 {
+        (self - other).chebyshev()
+    }
+}
+
+impl Vector {
+    pub const fn new(row: i32, column: i32) -> Self {
+        Self { row, column }
+    }
+
+    pub fn manhattan(self) -> u32 {
+        self.row.unsigned_abs() + self.column.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> u32 {
+        self.row.unsigned_abs().max(self.column.unsigned_abs())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, column): (i32, i32)) -> Self {
+        Self::new(row, column)
+    }
+}
+impl From<(i32, i32)> for Vector {
+    fn from((row, column): (i32, i32)) -> Self {
+        Self::new(row, column)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Self;
+
+    fn add(self, v: Vector) -> Self {
+        Self::new(self.row + v.row, self.column + v.column)
+    }
+}
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+impl Sub<Vector> for Point {
+    type Output = Self;
+
+    fn sub(self, v: Vector) -> Self {
+        self + -v
+    }
+}
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Self) -> Vector {
+        Vector::new(self.row - other.row, self.column - other.column)
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.row + other.row, self.column + other.column)
+    }
+}
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.column)
+    }
+}
+impl Mul<i32> for Vector {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.row * factor, self.column * factor)
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Point::new(1, 2);
+    let b = Point::new(4, -2);
+    assert_eq!(b - a, Vector::new(3, -4));
+    assert_eq!(a + (b - a), b);
+    assert_eq!(b - (b - a) * 2, Point::new(-2, 6));
+    assert_eq!(Vector::new(1, 1) + Vector::new(2, -3), Vector::new(3, -2));
+    assert_eq!(Vector::new(1, 1) - Vector::new(2, -3), Vector::new(-1, 4));
+
+    let mut c = a;
+    c += Vector::new(0, 1);
+    c -= Vector::new(1, 0);
+    assert_eq!(c, Point::new(0, 3));
+}
+
+#[test]
+fn test_distances() {
+    let a = Point::new(1, 2);
+    let b = Point::new(4, -2);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn test_index() {
+    assert_eq!(Point::from_index(7, 3), Point::new(2, 1));
+    assert_eq!(Point::new(2, 1).to_index(3, 3), Some(7));
+    assert_eq!(Point::new(3, 0).to_index(3, 3), None);
+    assert_eq!(Point::new(0, 3).to_index(3, 3), None);
+    assert_eq!(Point::new(-1, 0).to_index(3, 3), None);
+}
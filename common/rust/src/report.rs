@@ -0,0 +1,181 @@
+//! Machine-readable results for scripts and dashboards.
+
+use std::{
+    fmt::Write,
+    io::{self, BufRead, Read},
+};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{alloc::Allocs, solution::Timed};
+
+/// How to print answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Just the answer
+    #[default]
+    Text,
+    /// One JSON object per line with the answer, timings and input hash
+    Json,
+}
+
+/// The result of solving one part, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Hex SHA-256 of the raw input, as printed by `sha256sum`.
+    pub input_sha256: String,
+    /// Only reported by binaries that count allocations, such as the `aoc` runner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_allocs: Option<Allocs>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solve_allocs: Option<Allocs>,
+}
+impl Report {
+    pub fn new(day: u32, part: u32, timed: Timed, input: &[u8]) -> Self {
+        Self::streamed(day, part, timed, sha256_hex(input))
+    }
+
+    /// A report for an input that was never in memory as a whole, hashed by [`Hashing`].
+    pub fn streamed(day: u32, part: u32, timed: Timed, input_sha256: String) -> Self {
+        Self {
+            day,
+            part,
+            answer: timed.answer,
+            parse_ns: timed.parse.as_nanos() as u64,
+            solve_ns: timed.solve.as_nanos() as u64,
+            input_sha256,
+            parse_allocs: timed.parse_allocs,
+            solve_allocs: timed.solve_allocs,
+        }
+    }
+
+    /// Allocations of both phases together, with the higher of their peaks.
+    pub fn allocs(&self) -> Option<Allocs> {
+        let phases = self.parse_allocs.iter().chain(&self.solve_allocs);
+        phases.copied().reduce(|a, b| Allocs {
+            count: a.count + b.count,
+            bytes: a.bytes + b.bytes,
+            peak: a.peak.max(b.peak),
+        })
+    }
+
+    /// Prints the report in the given format.
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Text => println!("{}", self.answer),
+            Format::Json => println!("{}", self.to_json()),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a report is always valid JSON")
+    }
+}
+
+pub fn sha256_hex(input: &[u8]) -> String {
+    hex(&Sha256::digest(input))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        write!(hex, "{byte:02x}").unwrap();
+        hex
+    })
+}
+
+/// Hashes everything read through it, for reporting the hash of a streamed input.
+pub struct Hashing<R> {
+    inner: R,
+    hasher: Sha256,
+}
+impl<R: BufRead> Hashing<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// Hex SHA-256 of the bytes read so far.
+    pub fn finish(self) -> String {
+        hex(&self.hasher.finalize())
+    }
+}
+impl<R: BufRead> Read for Hashing<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+impl<R: BufRead> BufRead for Hashing<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // The bytes being consumed are still buffered, so this doesn't read anything.
+        if let Ok(buffered) = self.inner.fill_buf() {
+            self.hasher.update(&buffered[..amt]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+#[test]
+fn test_sha256_hex() {
+    assert_eq!(
+        sha256_hex(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_hashing() {
+    let mut reader = Hashing::new(io::BufReader::with_capacity(2, b"a\nbc".as_slice()));
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    reader.read_to_string(&mut line).unwrap();
+    assert_eq!(line, "a\nbc");
+    assert_eq!(reader.finish(), sha256_hex(b"a\nbc"));
+}
+
+#[test]
+fn test_to_json() {
+    use std::time::Duration;
+
+    let timed = Timed {
+        answer: "42".to_owned(),
+        parse: Duration::from_micros(3),
+        solve: Duration::from_millis(2),
+        parse_allocs: None,
+        solve_allocs: None,
+    };
+    let report = Report::new(7, 2, timed, b"abc");
+    insta::assert_snapshot!(report.to_json(), @r#"{"day":7,"part":2,"answer":"42","parse_ns":3000,"solve_ns":2000000,"input_sha256":"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"}"#);
+}
+
+#[test]
+fn test_to_json_allocs() {
+    let allocs = Allocs {
+        count: 2,
+        bytes: 300,
+        peak: 200,
+    };
+    let report = Report {
+        parse_allocs: Some(allocs),
+        solve_allocs: Some(Allocs::default()),
+        ..Report::new(1, 1, Timed::default(), b"")
+    };
+    let json = report.to_json();
+    assert!(json.ends_with(r#""parse_allocs":{"count":2,"bytes":300,"peak":200},"solve_allocs":{"count":0,"bytes":0,"peak":0}}"#));
+    assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    assert_eq!(report.allocs(), Some(allocs));
+}
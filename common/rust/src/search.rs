@@ -0,0 +1,399 @@
+//! Searches over graphs given by a function from each node to its neighbours, such as the cells
+//! of a [`Map`](crate::guard::Map) or the states of a guard walking through it.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use indexmap::IndexSet;
+
+/// The cheapest paths found from the starts of a search.
+#[derive(Debug, Clone)]
+pub struct Paths<N> {
+    /// The cost of the cheapest paths to every node reached, zero for the starts.
+    pub costs: HashMap<N, u64>,
+    /// The nodes one step before each node on its cheapest paths, none for the starts.
+    pub previous: HashMap<N, Vec<N>>,
+    /// The first goal reached, which is one of the cheapest to reach.
+    pub goal: Option<N>,
+}
+impl<N: Copy + Eq + Hash> Paths<N> {
+    fn new(starts: impl IntoIterator<Item = N>) -> Self {
+        let costs: HashMap<_, _> = starts.into_iter().map(|start| (start, 0)).collect();
+        let previous = costs.keys().map(|&start| (start, vec![])).collect();
+        Self {
+            costs,
+            previous,
+            goal: None,
+        }
+    }
+
+    /// Records a step from `from` to `to` costing `cost` in all, returning whether it is the
+    /// cheapest way to `to` so far.
+    fn step(&mut self, from: N, to: N, cost: u64) -> bool {
+        match self.costs.entry(to) {
+            Entry::Vacant(entry) => {
+                entry.insert(cost);
+            }
+            Entry::Occupied(mut entry) => match cost.cmp(entry.get()) {
+                Ordering::Less => {
+                    entry.insert(cost);
+                }
+                Ordering::Equal => {
+                    // Starts have no steps before them, even free ones back from a neighbour.
+                    let previous = self.previous.get_mut(&to).unwrap();
+                    if !previous.is_empty() && !previous.contains(&from) {
+                        previous.push(from);
+                    }
+                    return false;
+                }
+                Ordering::Greater => return false,
+            },
+        }
+        self.previous.insert(to, vec![from]);
+        true
+    }
+
+    pub fn cost(&self, node: N) -> Option<u64> {
+        self.costs.get(&node).copied()
+    }
+
+    /// One of the cheapest paths to `node`, from a start to `node` itself.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        let mut path = vec![node];
+        let mut previous = self.previous.get(&node)?;
+        while let Some(&node) = previous.first() {
+            path.push(node);
+            previous = &self.previous[&node];
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path to `node`, of which there can be very many, each visiting a node at
+    /// most once even where steps that cost nothing go round in a loop.
+    pub fn all_paths(&self, node: N) -> Vec<Vec<N>> {
+        let mut paths = vec![];
+        if self.previous.contains_key(&node) {
+            self.collect_paths(node, &mut vec![], &mut paths);
+        }
+        paths
+    }
+
+    /// Adds every path to `node` followed by `after`, which is reversed, to `paths`.
+    fn collect_paths(&self, node: N, after: &mut Vec<N>, paths: &mut Vec<Vec<N>>) {
+        after.push(node);
+        let previous = &self.previous[&node];
+        if previous.is_empty() {
+            paths.push(after.iter().rev().copied().collect());
+        }
+        for &before in previous {
+            if !after.contains(&before) {
+                self.collect_paths(before, after, paths);
+            }
+        }
+        after.pop();
+    }
+
+    /// Every node on any of the cheapest paths to `node`, without listing the paths.
+    pub fn on_paths(&self, node: N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        if self.previous.contains_key(&node) {
+            let mut stack = vec![node];
+            while let Some(node) = stack.pop() {
+                if nodes.insert(node) {
+                    stack.extend(&self.previous[&node]);
+                }
+            }
+        }
+        nodes
+    }
+}
+
+/// Breadth-first search, where every step costs 1, until the first goal is reached or every
+/// node reachable from the starts has been.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(starts);
+    let mut queue: VecDeque<_> = paths.costs.keys().copied().collect();
+    while let Some(node) = queue.pop_front() {
+        let cost = paths.costs[&node];
+        // Every way to the goal was seen by the time it was reached.
+        if paths.goal.is_some_and(|goal| cost >= paths.costs[&goal]) {
+            break;
+        }
+        if is_goal(node) {
+            paths.goal = Some(node);
+            continue;
+        }
+        for next in neighbours(node) {
+            if paths.step(node, next, cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, for steps of different costs, until the first goal is reached or every
+/// node reachable from the starts has been.
+///
+/// Steps may cost nothing, even in a loop.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// A*, which is Dijkstra's algorithm looking at the nodes that `heuristic` puts nearest to a goal
+/// first.
+///
+/// The heuristic must never overestimate, nor drop by more than the cost of a step, for the
+/// paths found to be the cheapest.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> u64,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> Paths<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut paths = Paths::new(starts);
+    let mut queue: BinaryHeap<_> = paths
+        .costs
+        .keys()
+        .map(|&node| Queued {
+            estimate: heuristic(node),
+            cost: 0,
+            node,
+        })
+        .collect();
+    let mut done = HashSet::new();
+    while let Some(Queued {
+        estimate,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        // Nodes as cheap as the goal may still be steps on other ways to it.
+        if paths.goal.is_some_and(|goal| estimate > paths.costs[&goal]) {
+            break;
+        }
+        if !done.insert(node) {
+            continue;
+        }
+        if paths.goal.is_none() && is_goal(node) {
+            paths.goal = Some(node);
+            continue;
+        }
+        for (next, step) in neighbours(node) {
+            let cost = cost + step;
+            if paths.step(node, next, cost) {
+                queue.push(Queued {
+                    estimate: cost + heuristic(next),
+                    cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// A node waiting in the queue of [`astar`], first out when its estimate is lowest.
+struct Queued<N> {
+    estimate: u64,
+    cost: u64,
+    node: N,
+}
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<N> Eq for Queued<N> {}
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Of equal estimates, the one furthest along is probably nearest to a goal.
+        (other.estimate, self.cost).cmp(&(self.estimate, other.cost))
+    }
+}
+
+/// Every node reachable from `start`, nearest first.
+pub fn flood_fill<N, I>(start: N, mut neighbours: impl FnMut(N) -> I) -> IndexSet<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut region = IndexSet::from([start]);
+    let mut next = 0;
+    while let Some(&node) = region.get_index(next) {
+        region.extend(neighbours(node));
+        next += 1;
+    }
+    region
+}
+
+/// Splits `nodes` into the groups reachable from each other, in the order of their first nodes.
+///
+/// `neighbours` should go both ways, so that a node is a neighbour of its neighbours.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Vec<IndexSet<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+    for node in nodes {
+        if !seen.contains(&node) {
+            let component = flood_fill(node, &mut neighbours);
+            seen.extend(component.iter().copied());
+            components.push(component);
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+use crate::{
+    direction::Direction,
+    guard::{Guard, Map},
+    point::Point,
+};
+
+#[cfg(test)]
+const MAZE: &str = "\
+.....#
+.###.#
+...#..
+.#...#
+.#.#..";
+
+#[test]
+fn test_bfs() {
+    let map = Map::new(MAZE).unwrap();
+    let (start, end) = (Point::new(0, 0), Point::new(4, 5));
+    let paths = bfs([start], |p| map.open_neighbours(p), |p| p == end);
+    assert_eq!(paths.goal, Some(end));
+    assert_eq!(paths.cost(end), Some(9));
+    let path = paths.path(end).unwrap();
+    assert_eq!((path[0], path.len()), (start, 10));
+    assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+    // Along the top, or down and through the middle.
+    assert_eq!(paths.all_paths(end).len(), 2);
+    assert_eq!(paths.on_paths(end).len(), 16);
+    assert_eq!(paths.path(Point::new(1, 1)), None);
+    assert!(paths.all_paths(Point::new(1, 1)).is_empty());
+
+    let everywhere = bfs([start], |p| map.open_neighbours(p), |_| false);
+    assert_eq!(everywhere.goal, None);
+    assert_eq!(everywhere.costs.len(), 20);
+}
+
+#[test]
+fn test_all_paths_in_open_square() {
+    let map = Map::new("...\n...\n...").unwrap();
+    let end = Point::new(2, 2);
+    let paths = bfs([Point::new(0, 0)], |p| map.open_neighbours(p), |p| p == end);
+    assert_eq!(paths.all_paths(end).len(), 6);
+    assert_eq!(paths.on_paths(end).len(), 9);
+}
+
+#[test]
+fn test_dijkstra_and_astar_with_turns() {
+    let map = Map::new(MAZE).unwrap();
+    let start = Guard {
+        position: Point::new(0, 0),
+        direction: Direction::Right,
+    };
+    let end = Point::new(4, 5);
+    let is_goal = |guard: Guard| guard.position == end;
+    let moves = |guard| map.moves(guard, 1000);
+
+    let cheapest = dijkstra([start], moves, is_goal);
+    let goal = cheapest.goal.unwrap();
+    // Along the top, which turns twice, rather than through the middle, which turns six times.
+    assert_eq!(cheapest.cost(goal), Some(2009));
+    assert_eq!(cheapest.all_paths(goal).len(), 1);
+
+    let estimate = |guard: Guard| guard.position.manhattan(end) as u64;
+    let guided = astar([start], moves, estimate, is_goal);
+    assert_eq!(guided.cost(guided.goal.unwrap()), Some(2009));
+    assert!(guided.costs.len() <= cheapest.costs.len());
+
+    // From several starts at once, the nearest one wins.
+    let near = Guard {
+        position: Point::new(4, 4),
+        ..start
+    };
+    let paths = dijkstra([start, near], moves, is_goal);
+    assert_eq!(paths.cost(paths.goal.unwrap()), Some(1));
+    assert_eq!(paths.path(paths.goal.unwrap()).unwrap()[0], near);
+}
+
+#[test]
+fn test_free_loops() {
+    // Free steps back and forth between 0 and 1, and between 2 and 3.
+    let edges = |node: u32| match node {
+        0 => vec![(1, 0)],
+        1 => vec![(0, 0), (2, 1)],
+        2 => vec![(3, 0)],
+        3 => vec![(2, 0), (4, 1)],
+        _ => vec![],
+    };
+    let paths = dijkstra([0], edges, |node| node == 4);
+    assert_eq!(paths.cost(4), Some(2));
+    assert_eq!(paths.path(0), Some(vec![0]));
+    assert_eq!(paths.path(4), Some(vec![0, 1, 2, 3, 4]));
+    assert_eq!(paths.all_paths(4), [[0, 1, 2, 3, 4]]);
+    assert_eq!(paths.on_paths(4).len(), 5);
+
+    let everywhere = dijkstra([0, 1], edges, |_| false);
+    assert_eq!(everywhere.path(1), Some(vec![1]));
+    assert_eq!(everywhere.all_paths(3), [[1, 2, 3]]);
+}
+
+#[test]
+fn test_components() {
+    let map = Map::new(MAZE).unwrap();
+    let region = flood_fill(Point::new(0, 0), |p| map.open_neighbours(p));
+    assert_eq!(region.len(), 20);
+    assert_eq!(region[0], Point::new(0, 0));
+
+    let map = Map::new("..#..\n..#.#\n#.#.#").unwrap();
+
+    let free = map
+        .obstacles
+        .iter()
+        .filter(|(_, &obstacle)| !obstacle)
+        .map(|(position, _)| position);
+    let components = connected_components(free, |p| map.open_neighbours(p));
+    let sizes: Vec<_> = components.iter().map(IndexSet::len).collect();
+    assert_eq!(sizes, [5, 4]);
+}
@@ -0,0 +1,194 @@
+//! The interface shared by every day's solution.
+
+use std::{
+    fmt::Display,
+    io::{BufRead, BufReader},
+    time::{Duration, Instant},
+};
+
+use clap::{Parser, ValueEnum};
+
+use crate::{
+    alloc::{self, Allocs},
+    report::{Format, Hashing, Report},
+};
+
+/// A puzzle whose input is parsed once and then solved for both parts.
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+
+    /// Solves a single part while reading the input, in constant memory.
+    ///
+    /// Only days whose parts work line by line or as a scan have such a streaming mode.
+    fn stream(input: impl BufRead, part: u32) -> anyhow::Result<Self::Answer> {
+        let _ = (input, part);
+        anyhow::bail!("day {} has no streaming mode", Self::DAY)
+    }
+
+    /// Like [`Solution::stream`], but also measures the time and allocations, all of which
+    /// count as solving.
+    fn stream_timed(input: impl BufRead, part: u32) -> anyhow::Result<Timed> {
+        let ((answer, solve), solve_allocs) =
+            alloc::measure(|| timed(|| Self::stream(input, part)));
+        Ok(Timed {
+            answer: answer?.to_string(),
+            solve,
+            solve_allocs,
+            ..Timed::default()
+        })
+    }
+
+    /// Parses the input and solves a single part.
+    fn solve(input: &[u8], part: u32) -> anyhow::Result<String> {
+        Ok(Self::solve_timed(input, part)?.answer)
+    }
+
+    /// Like [`Solution::solve`], but also measures the time and allocations of each phase.
+    fn solve_timed(input: &[u8], part: u32) -> anyhow::Result<Timed> {
+        let solve: fn(&Self::Input) -> String = match part {
+            1 => |input: &Self::Input| Self::part1(input).to_string(),
+            2 => |input: &Self::Input| Self::part2(input).to_string(),
+            _ => anyhow::bail!("no such part: {part}"),
+        };
+
+        let ((input, parse), parse_allocs) = alloc::measure(|| timed(|| Self::parse(input)));
+        let input = input?;
+        let ((answer, solve), solve_allocs) = alloc::measure(|| timed(|| solve(&input)));
+
+        Ok(Timed {
+            answer,
+            parse,
+            solve,
+            parse_allocs,
+            solve_allocs,
+        })
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// An answer together with the time spent in each phase.
+#[derive(Debug, Clone, Default)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Allocations of each phase, if the binary counts them.
+    pub parse_allocs: Option<Allocs>,
+    pub solve_allocs: Option<Allocs>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+#[derive(Parser)]
+struct Args {
+    /// Which part of the puzzle to solve
+    #[arg(long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Solve while reading the input instead of reading it all first, for inputs too large
+    /// for memory; needs a single part
+    #[arg(long)]
+    stream: bool,
+}
+
+/// Entry point for a day's binary: reads the input from stdin and prints the answers.
+pub fn main<S: Solution>() -> anyhow::Result<()> {
+    let args = Args::parse();
+    if args.stream {
+        let part = match args.part {
+            Part::One => 1,
+            Part::Two => 2,
+            Part::Both => anyhow::bail!("--stream reads the input once, so it needs --part"),
+        };
+        let mut input = Hashing::new(std::io::stdin().lock());
+        let timed = S::stream_timed(&mut input, part)?;
+        Report::streamed(S::DAY, part, timed, input.finish()).print(args.format);
+        return Ok(());
+    }
+    let bytes = crate::input::bytes(BufReader::new(std::io::stdin()))?;
+
+    let ((input, parse), parse_allocs) = alloc::measure(|| timed(|| S::parse(&bytes)));
+    let input = input?;
+
+    let parts = match args.part {
+        Part::One => &[1][..],
+        Part::Two => &[2],
+        Part::Both => &[1, 2],
+    };
+    for &part in parts {
+        let ((answer, solve), solve_allocs) = alloc::measure(|| {
+            timed(|| match part {
+                1 => S::part1(&input).to_string(),
+                _ => S::part2(&input).to_string(),
+            })
+        });
+
+        let timed = Timed {
+            answer,
+            parse,
+            solve,
+            parse_allocs,
+            solve_allocs,
+        };
+        Report::new(S::DAY, part, timed, &bytes).print(args.format);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    const DAY: u32 = 0;
+    type Input = Vec<i32>;
+    type Answer = i32;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        crate::input::lines(input, |s| crate::error::parse_at(s, s))
+    }
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input.iter().sum()
+    }
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.iter().product()
+    }
+}
+
+#[test]
+fn test_solve() {
+    assert_eq!(Sum::solve(b"2\n3\n4\n", 1).unwrap(), "9");
+    assert_eq!(Sum::solve(b"2\n3\n4\n", 2).unwrap(), "24");
+    assert!(Sum::solve(b"2\n3\n4\n", 3).is_err());
+    assert!(Sum::solve(b"x\n", 1).is_err());
+}
+
+#[test]
+fn test_solve_timed() {
+    let timed = Sum::solve_timed(b"2\n3\n4\n", 2).unwrap();
+    assert_eq!(timed.answer, "24");
+    // The unit tests count allocations, see `alloc`.
+    assert!(timed.parse_allocs.unwrap().count > 0);
+    assert_eq!(timed.solve_allocs.unwrap().count, 1);
+    assert!(Sum::solve_timed(b"x\n", 3).is_err());
+}
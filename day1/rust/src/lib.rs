@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use aoc2024_common::{input::pairs, solution::Solution};
+
+pub struct Day1;
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Vec<(i32, i32)>;
+    // Wider than the IDs, so that long generated lists don't overflow.
+    type Answer = i64;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        pairs(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        let (mut list1, mut list2): (Vec<_>, Vec<_>) = input.iter().copied().unzip();
+        list1.sort_unstable();
+        list2.sort_unstable();
+        list1
+            .into_iter()
+            .zip(list2)
+            .map(|(a, b)| (i64::from(a) - i64::from(b)).abs())
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        let (list1, list2): (Vec<_>, Vec<_>) = input.iter().copied().unzip();
+        let counts = list2.into_iter().fold(HashMap::new(), |mut acc, x| {
+            *acc.entry(x).or_insert(0i64) += 1;
+            acc
+        });
+        list1
+            .into_iter()
+            .map(|x| i64::from(x) * counts.get(&x).unwrap_or(&0))
+            .sum()
+    }
+}
+
+#[test]
+fn parse_input() {
+    let txt = include_bytes!("../../examples/example.txt");
+    let input = Day1::parse(txt).unwrap();
+    insta::assert_debug_snapshot!(input);
+}
@@ -0,0 +1,124 @@
+use std::io::BufRead;
+
+use aoc2024_common::{
+    input::{for_each_line, report, reports},
+    solution::Solution,
+};
+use itertools::Itertools;
+
+pub mod naive;
+
+pub fn safe(report: impl Iterator<Item = i32> + Clone) -> bool {
+    let increasing = report.clone().tuple_windows().all(|(a, b)| a < b);
+    let decreasing = report.clone().tuple_windows().all(|(a, b)| a > b);
+
+    let gradual = report
+        .tuple_windows()
+        .all(|(a, b)| (1..=3).contains(&(i64::from(a) - i64::from(b)).abs()));
+
+    gradual && (increasing || decreasing)
+}
+
+/// Whether the report is safe after removing at most one level.
+///
+/// Only the two levels of the first bad step can be worth removing: removing any other level
+/// leaves that step in place. So this checks a single direction at a time in linear time.
+fn safe_dampened(report: &[i32]) -> bool {
+    let step = |a: i32, b: i32| i64::from(b) - i64::from(a);
+    let directions: [fn(i64) -> bool; 2] = [
+        |step| (1..=3).contains(&step),
+        |step| (-3..=-1).contains(&step),
+    ];
+    directions.iter().any(|good| {
+        let Some(bad) = report.windows(2).position(|w| !good(step(w[0], w[1]))) else {
+            return true;
+        };
+        [bad, bad + 1].iter().any(|&skip| {
+            report
+                .iter()
+                .enumerate()
+                .filter(|&(idx, _)| idx != skip)
+                .tuple_windows()
+                .all(|((_, &a), (_, &b))| good(step(a, b)))
+        })
+    })
+}
+
+pub struct Day2;
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Vec<i32>>;
+    type Answer = usize;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        reports(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input
+            .iter()
+            .filter(|report| safe(report.iter().copied()))
+            .count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input.iter().filter(|report| safe_dampened(report)).count()
+    }
+
+    fn stream(input: impl BufRead, part: u32) -> anyhow::Result<Self::Answer> {
+        let mut count = 0;
+        for_each_line(input, |line| {
+            let report = report(line)?;
+            let safe = match part {
+                1 => safe(report.iter().copied()),
+                _ => safe_dampened(&report),
+            };
+            count += usize::from(safe);
+            Ok(())
+        })?;
+        Ok(count)
+    }
+}
+
+#[test]
+fn parse_input() {
+    let txt = include_bytes!("../../examples/example.txt");
+    let input = Day2::parse(txt).unwrap();
+    insta::assert_debug_snapshot!(input);
+}
+
+#[test]
+fn stream_example() {
+    let bytes = include_bytes!("../../examples/example.txt");
+    let input = Day2::parse(bytes).unwrap();
+    assert_eq!(
+        Day2::stream(bytes.as_slice(), 1).unwrap(),
+        Day2::part1(&input)
+    );
+    assert_eq!(
+        Day2::stream(bytes.as_slice(), 2).unwrap(),
+        Day2::part2(&input)
+    );
+}
+
+#[test]
+fn extreme_levels() {
+    // Steps between levels at both ends of the range overflowed i32.
+    let bytes = b"-2147483648 2147483647\n2147483647 -2147483648 -2147483647\n";
+    let input = Day2::parse(bytes).unwrap();
+    assert_eq!(Day2::part1(&input), 0);
+    assert_eq!(Day2::part2(&input), 2);
+    assert_eq!(naive::part2(&input), 2);
+    assert_eq!(Day2::stream(bytes.as_slice(), 2).unwrap(), 2);
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn part2_matches_naive(
+        // Short reports of close levels, so that most of them are safe or nearly so.
+        input in proptest::collection::vec(proptest::collection::vec(0i32..12, 1..9), 0..20)
+    ) {
+        proptest::prop_assert_eq!(Day2::part2(&input), naive::part2(&input));
+    }
+}
@@ -0,0 +1,14 @@
+//! The original brute-force solution, kept as a reference oracle for the faster one.
+
+use crate::safe;
+
+/// Tries removing each level in turn, which is quadratic in the report length.
+pub fn part2(input: &[Vec<i32>]) -> usize {
+    input
+        .iter()
+        .filter(|report| {
+            (0..report.len())
+                .any(|idx| safe(report[0..idx].iter().chain(&report[idx + 1..]).copied()))
+        })
+        .count()
+}
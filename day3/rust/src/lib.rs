@@ -0,0 +1,116 @@
+use std::io::BufRead;
+
+use aoc2024_common::{
+    parse::{parse_lit, parse_mul},
+    solution::Solution,
+};
+fn parse_dodont(input: &[u8]) -> Option<(bool, &[u8])> {
+    #[allow(clippy::manual_map)]
+    if let Some(rest) = parse_lit(input, b"do()") {
+        Some((true, rest))
+    } else if let Some(rest) = parse_lit(input, b"don't()") {
+        Some((false, rest))
+    } else {
+        None
+    }
+}
+
+/// Length of the longest instruction, `mul(123,456)`.
+const LONGEST: usize = 12;
+
+/// Running sums of the products of all `mul` instructions, and of just the enabled ones.
+#[derive(Default)]
+struct Sums {
+    all: i64,
+    enabled: i64,
+    disabled: bool,
+}
+impl Sums {
+    /// Scans `memory`, returning how much of it was scanned.
+    ///
+    /// Unless this is the `last` of the memory, the scan stops short of where an instruction
+    /// might continue past the end, so that the rest can be scanned with what comes next.
+    fn scan(&mut self, memory: &[u8], last: bool) -> usize {
+        let mut input = memory;
+        while !input.is_empty() && (last || input.len() >= LONGEST) {
+            if let Some(((a, b), rest)) = parse_mul(input) {
+                let product = i64::from(a * b);
+                self.all += product;
+                if !self.disabled {
+                    self.enabled += product;
+                }
+                input = rest;
+            } else if let Some((enabled, rest)) = parse_dodont(input) {
+                self.disabled = !enabled;
+                input = rest;
+            } else {
+                input = &input[1..];
+            }
+        }
+        memory.len() - input.len()
+    }
+
+    fn part(&self, part: u32) -> i64 {
+        match part {
+            1 => self.all,
+            _ => self.enabled,
+        }
+    }
+}
+
+pub struct Day3;
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<u8>;
+    // Each product fits in an `i32`, but long generated inputs overflow the sum.
+    type Answer = i64;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        Ok(input.to_vec())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        let mut sums = Sums::default();
+        sums.scan(input, true);
+        sums.part(1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        let mut sums = Sums::default();
+        sums.scan(input, true);
+        sums.part(2)
+    }
+
+    fn stream(mut input: impl BufRead, part: u32) -> anyhow::Result<Self::Answer> {
+        let mut sums = Sums::default();
+        // What is left over from the previous chunk, followed by the next one.
+        let mut window = vec![];
+        loop {
+            let chunk = input.fill_buf()?;
+            let last = chunk.is_empty();
+            window.extend_from_slice(chunk);
+            let read = chunk.len();
+            input.consume(read);
+
+            let scanned = sums.scan(&window, last);
+            window.drain(..scanned);
+            if last {
+                return Ok(sums.part(part));
+            }
+        }
+    }
+}
+
+#[test]
+fn stream_across_chunks() {
+    use std::io::BufReader;
+
+    let bytes = include_bytes!("../../examples/example2.txt");
+    let input = Day3::parse(bytes).unwrap();
+    // Chunks so small that every instruction is split between them.
+    for capacity in 1..=LONGEST {
+        let stream = |part| Day3::stream(BufReader::with_capacity(capacity, &bytes[..]), part);
+        assert_eq!(stream(1).unwrap(), Day3::part1(&input));
+        assert_eq!(stream(2).unwrap(), Day3::part2(&input));
+    }
+}
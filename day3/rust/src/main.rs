@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc2024_common::solution::main::<day3_rust::Day3>()
+}
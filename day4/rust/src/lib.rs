@@ -0,0 +1,66 @@
+use aoc2024_common::{
+    direction::Direction8,
+    grid::Grid,
+    input::chars,
+    point::{Point, Vector},
+    solution::Solution,
+};
+
+fn input_has_word(input: &Grid<char>, word: &str, start: Point, step: Vector) -> bool {
+    word.chars()
+        .enumerate()
+        .all(|(i, ch)| input.get(start + step * i as i32) == Some(&ch))
+}
+
+/// Counts occurrences of `word` in any of the eight directions.
+fn count_words(input: &Grid<char>, word: &str) -> usize {
+    itertools::iproduct!(input.positions(), Direction8::all())
+        .filter(|&(start, direction)| input_has_word(input, word, start, direction.vector()))
+        .count()
+}
+
+/// Counts pairs of `word`s crossing diagonally in an X shape.
+fn count_crosses(input: &Grid<char>, word: &str) -> usize {
+    use Direction8::*;
+
+    let end = word.len() as i32 - 1;
+    let (down, across) = (Down.vector() * end, Right.vector() * end);
+    itertools::iproduct!(input.positions(), [true, false], [true, false])
+        .filter(|&(corner, dir1, dir2)| {
+            (if dir1 {
+                input_has_word(input, word, corner, DownRight.vector())
+            } else {
+                input_has_word(input, word, corner + down + across, UpLeft.vector())
+            }) && if dir2 {
+                input_has_word(input, word, corner + across, DownLeft.vector())
+            } else {
+                input_has_word(input, word, corner + down, UpRight.vector())
+            }
+        })
+        .count()
+}
+
+pub struct Day4;
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        chars(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        count_words(input, "XMAS")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        count_crosses(input, "MAS")
+    }
+}
+
+#[test]
+fn parse_example() {
+    let bytes = include_bytes!("../../examples/example.txt");
+    insta::assert_debug_snapshot!(Day4::parse(bytes).unwrap());
+}
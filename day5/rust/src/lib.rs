@@ -0,0 +1,127 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use aoc2024_common::{
+    error::{self, ParseError},
+    input::{rules_and_updates, Rules as RuleList, Updates},
+    solution::Solution,
+};
+
+struct Rules<T> {
+    /// `map[a].contains(b)` represents a rule `a > b`.
+    map: HashMap<T, HashSet<T>>,
+}
+impl<T: Clone + Hash + Eq> Rules<T> {
+    fn new(rules: &[(T, T)]) -> Self {
+        let map = rules
+            .iter()
+            .fold(HashMap::new(), |mut acc: HashMap<T, HashSet<T>>, (b, a)| {
+                acc.entry(a.clone()).or_default().insert(b.clone());
+                acc
+            });
+        Self { map }
+    }
+    fn iter_lesser(&self, x: &T) -> impl Iterator<Item = T> + '_ {
+        self.map.get(x).into_iter().flatten().cloned()
+    }
+    /// The items in rule order, or `None` if the rules between them form a cycle.
+    fn sort_topologically(&self, items: &[T]) -> Option<Vec<T>> {
+        let mut unused: HashSet<T> = items.iter().cloned().collect();
+        let mut sorted = vec![];
+        while let Some(x) = unused.iter().next() {
+            let mut x = x.clone();
+            // Climb down with unused numbers for as long as we can
+            let mut steps = 0;
+            while let Some(y) = self.iter_lesser(&x).find(|y| unused.contains(y)) {
+                x = y;
+                steps += 1;
+                if steps > unused.len() {
+                    return None;
+                }
+            }
+            // Push the smallest number
+            unused.remove(&x);
+            sorted.push(x);
+        }
+        Some(sorted)
+    }
+}
+
+pub struct Day5;
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = (RuleList, Updates);
+    // Wider than the page numbers, so that the sum of middle pages cannot overflow.
+    type Answer = i64;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        let (rule_list, updates) = rules_and_updates(input)?;
+        let rules = Rules::new(&rule_list);
+        if let Some(index) = updates
+            .iter()
+            .position(|update| rules.sort_topologically(update).is_none())
+        {
+            let text = error::utf8(input)?;
+            let (_, second) = text.split_once("\n\n").expect("checked while parsing");
+            let line = second.lines().nth(index).expect("one line per update");
+            Err(ParseError::at(
+                text,
+                line,
+                "the rules order these pages in a cycle",
+            ))?;
+        }
+        Ok((rule_list, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Self::Answer {
+        updates
+            .iter()
+            .filter(|update| {
+                rules.iter().all(|(a, b)| {
+                    let mut found_second = false;
+                    for x in *update {
+                        if x == a {
+                            return !found_second;
+                        } else if x == b {
+                            found_second = true;
+                        }
+                    }
+                    true
+                })
+            })
+            .map(|update| i64::from(update[update.len() / 2]))
+            .sum()
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Self::Answer {
+        // Make a map of rules that's easy to look up
+        let rules = Rules::new(rules);
+
+        updates
+            .iter()
+            // Sort the numbers in each update, discarding updates that were already sorted.
+            .filter_map(move |update| {
+                let sorted_update = rules
+                    .sort_topologically(update)
+                    .expect("checked while parsing");
+                (*update != sorted_update).then_some(sorted_update)
+            })
+            .map(|update| i64::from(update[update.len() / 2]))
+            .sum()
+    }
+}
+
+#[test]
+fn parse_example() {
+    let bytes = include_bytes!("../../examples/example.txt");
+    insta::assert_debug_snapshot!(Day5::parse(bytes).unwrap());
+}
+
+#[test]
+fn parse_cycle() {
+    let err = Day5::parse(b"1|2\n2|3\n3|1\n\n1,2\n1,2,3\n").unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.line, err.snippet.as_str()), (6, "1,2,3"));
+}
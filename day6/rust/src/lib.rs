@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use aoc2024_common::{
+    bitset::Set,
+    error::utf8,
+    guard::{Board, Dense, Storage},
+    solution::Solution,
+};
+use itertools::Itertools;
+
+pub mod naive;
+
+pub struct Day6;
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Board;
+    type Answer = usize;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        Ok(Board::from_str(utf8(input)?.trim_end())?)
+    }
+
+    fn part1(board: &Self::Input) -> Self::Answer {
+        part1::<Dense>(board)
+    }
+
+    fn part2(board: &Self::Input) -> Self::Answer {
+        part2::<Dense>(board)
+    }
+}
+
+/// Counts the cells the guard walks through, keeping them in `S`.
+pub fn part1<S: Storage>(board: &Board) -> usize {
+    // We are going to straight up simulate the guard walking.
+    let mut cells: S::Cells = board.map.new_set();
+    board.walk(&mut board.map.new_set::<_, S::States>(), |guard| {
+        cells.insert(guard.position);
+    });
+    cells.len()
+}
+
+/// Counts the cells where an obstacle makes the guard loop, keeping the cells and states of
+/// each walk in `S`.
+pub fn part2<S: Storage>(board: &Board) -> usize {
+    // An obstacle off the guard's path changes nothing, so only cells on it are tried.
+    // The walk up to the first visit of a cell is unaffected by an obstacle there, so each
+    // simulation starts from the step just before it.
+    let mut seen: S::States = board.map.new_set();
+    let mut history = vec![];
+    let already_looped = board.walk(&mut seen, |guard| history.push(guard));
+    let mut tried: S::Cells = board.map.new_set();
+    tried.insert(board.guard.position);
+    let mut board = board.clone();
+    let on_path = history
+        .iter()
+        .tuple_windows()
+        .filter(|(before, guard)| {
+            if !tried.insert(guard.position) {
+                return false;
+            }
+            board.guard = **before;
+            board.map.set_obstacle(guard.position, true);
+            let looped = board.walk(&mut seen, |_| {});
+            board.map.set_obstacle(guard.position, false);
+            looped
+        })
+        .count();
+
+    if already_looped {
+        // The guard keeps looping whatever is placed off the path.
+        let free = board
+            .map
+            .obstacles
+            .iter()
+            .filter(|(_, &obstacle)| !obstacle)
+            .count();
+        on_path + free - tried.len()
+    } else {
+        on_path
+    }
+}
+
+#[test]
+fn example_round_trip() {
+    let text = include_str!("../../examples/example.txt");
+    let board = Board::from_str(text).unwrap();
+    assert_eq!(text, board.to_string());
+    insta::assert_snapshot!(board);
+}
+
+#[cfg(test)]
+fn board() -> impl proptest::strategy::Strategy<Value = Board> {
+    use proptest::prelude::*;
+
+    (3usize..9, 3usize..9)
+        .prop_flat_map(|(height, width)| {
+            (
+                prop::collection::vec(prop::bool::weighted(0.2), height * width),
+                0..height * width,
+                prop::sample::select(vec!['^', 'v', '<', '>']),
+                Just(width),
+            )
+        })
+        .prop_map(|(obstacles, guard, direction, width)| {
+            let text = obstacles
+                .iter()
+                .enumerate()
+                .map(|(idx, &obstacle)| match idx {
+                    _ if idx == guard => direction,
+                    _ if obstacle => '#',
+                    _ => '.',
+                })
+                .chunks(width)
+                .into_iter()
+                .map(|row| row.collect::<String>())
+                .join("\n");
+            Board::from_str(&text).unwrap()
+        })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn part1_sparse_matches_dense(board in board()) {
+        use aoc2024_common::guard::Sparse;
+
+        proptest::prop_assert_eq!(part1::<Sparse>(&board), part1::<Dense>(&board));
+    }
+
+    #[test]
+    fn part2_matches_naive(board in board()) {
+        use aoc2024_common::guard::Sparse;
+
+        proptest::prop_assert_eq!(Day6::part2(&board), naive::part2::<Sparse>(&board));
+        proptest::prop_assert_eq!(part2::<Sparse>(&board), naive::part2::<Dense>(&board));
+    }
+}
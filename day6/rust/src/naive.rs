@@ -0,0 +1,20 @@
+//! The original brute-force solution, kept as a reference oracle for the faster one.
+
+use aoc2024_common::guard::{Board, Storage};
+
+/// Tries an obstacle on every free cell of the map and simulates the whole walk each time,
+/// keeping the states of each walk in `S`.
+pub fn part2<S: Storage>(board: &Board) -> usize {
+    let mut seen: S::States = board.map.new_set();
+    board
+        .map
+        .obstacles
+        .positions()
+        .filter(|&position| !board.map.is_obstacle(position) && board.guard.position != position)
+        .filter(|&position| {
+            let mut board = board.clone();
+            board.map.set_obstacle(position, true);
+            board.walk(&mut seen, |_| {})
+        })
+        .count()
+}
@@ -0,0 +1,168 @@
+use std::io::BufRead;
+
+use aoc2024_common::{
+    input::{self, equations, for_each_line},
+    solution::Solution,
+};
+
+pub mod naive;
+
+/// Whether some choice of operators makes `values` evaluate to `target`.
+///
+/// Works backwards from the last value, which prunes most choices: multiplication is only
+/// possible if it divides the target, and concatenation only if the target ends with it.
+fn solvable(target: u64, values: &[u64], concat: bool) -> bool {
+    let Some((&last, rest)) = values.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return target == last;
+    }
+    if target >= last && solvable(target - last, rest, concat) {
+        return true;
+    }
+    if last == 0 {
+        // Multiplying by zero gives zero whatever comes before.
+        if target == 0 {
+            return true;
+        }
+    } else if target % last == 0 && solvable(target / last, rest, concat) {
+        return true;
+    }
+    if concat {
+        // Wide enough for the shift past the largest `u64`. Zero is one digit too.
+        let shift = 10u128.pow(last.checked_ilog10().unwrap_or(0) + 1);
+        let target = u128::from(target);
+        if target % shift == u128::from(last) && solvable((target / shift) as u64, rest, concat) {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day7;
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<(u64, Vec<u64>)>;
+    // u128 holds the sum of up to 2^64 u64 test values.
+    type Answer = u128;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        equations(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        input
+            .iter()
+            .filter(|(test_value, values)| solvable(*test_value, values, false))
+            .map(|&(test_value, _)| u128::from(test_value))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        input
+            .iter()
+            .filter(|(test_value, values)| solvable(*test_value, values, true))
+            .map(|&(test_value, _)| u128::from(test_value))
+            .sum()
+    }
+
+    fn stream(input: impl BufRead, part: u32) -> anyhow::Result<Self::Answer> {
+        let mut sum = 0;
+        for_each_line(input, |line| {
+            let (test_value, values) = input::equation(line)?;
+            if solvable(test_value, &values, part == 2) {
+                sum += u128::from(test_value);
+            }
+            Ok(())
+        })?;
+        Ok(sum)
+    }
+}
+
+#[test]
+fn parse_example() {
+    let bytes = include_bytes!("../../examples/example.txt");
+    insta::assert_debug_snapshot!(Day7::parse(bytes).unwrap());
+}
+
+#[test]
+fn stream_example() {
+    let bytes = include_bytes!("../../examples/example.txt");
+    let input = Day7::parse(bytes).unwrap();
+    assert_eq!(
+        Day7::stream(bytes.as_slice(), 1).unwrap(),
+        Day7::part1(&input)
+    );
+    assert_eq!(
+        Day7::stream(bytes.as_slice(), 2).unwrap(),
+        Day7::part2(&input)
+    );
+}
+
+#[cfg(test)]
+fn equation() -> impl proptest::strategy::Strategy<Value = (u64, Vec<u64>)> {
+    use proptest::prelude::*;
+
+    // Small values so that no choice of operators overflows, and a target that is often
+    // reachable so that both outcomes are exercised.
+    (
+        prop::collection::vec(0u64..30, 1..7),
+        prop::collection::vec(0u8..3, 6),
+        any::<bool>(),
+        0u64..5000,
+    )
+        .prop_map(|(values, operators, reachable, random)| {
+            let target = if reachable {
+                values[1..]
+                    .iter()
+                    .zip(operators)
+                    .fold(values[0], |acc, (&x, op)| match op {
+                        0 => acc * x,
+                        1 => acc + x,
+                        _ => acc * 10u64.pow(x.checked_ilog10().unwrap_or(0) + 1) + x,
+                    })
+            } else {
+                random
+            };
+            (target, values)
+        })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn part1_matches_naive(input in proptest::collection::vec(equation(), 0..10)) {
+        proptest::prop_assert_eq!(Day7::part1(&input), naive::part1(&input));
+    }
+
+    #[test]
+    fn part2_matches_naive(input in proptest::collection::vec(equation(), 0..10)) {
+        proptest::prop_assert_eq!(Day7::part2(&input), naive::part2(&input));
+    }
+}
+
+#[test]
+fn sum_past_u64() {
+    let bytes =
+        b"18446744073709551615: 18446744073709551615\n18446744073709551615: 18446744073709551615\n";
+    let input = Day7::parse(bytes).unwrap();
+    let expected = 2 * u128::from(u64::MAX);
+    assert_eq!(Day7::part1(&input), expected);
+    assert_eq!(Day7::part2(&input), expected);
+    assert_eq!(naive::part2(&input), expected);
+    assert_eq!(Day7::stream(bytes.as_slice(), 1).unwrap(), expected);
+}
+
+#[test]
+fn concat_zero() {
+    let input = Day7::parse(b"10: 1 0\n100: 10 0\n").unwrap();
+    assert_eq!(Day7::part1(&input), 0);
+    assert_eq!(Day7::part2(&input), 110);
+    assert_eq!(naive::part2(&input), 110);
+}
+
+#[test]
+fn concat_huge_value() {
+    assert!(!solvable(5, &[1, 10_000_000_000_000_000_000], true));
+}
@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use aoc2024_common::{antenna::AntennaMap, error::utf8, solution::Solution};
+use indexmap::IndexSet;
+
+pub struct Day8;
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = AntennaMap;
+    type Answer = usize;
+
+    fn parse(input: &[u8]) -> anyhow::Result<Self::Input> {
+        Ok(AntennaMap::from_str(utf8(input)?)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer {
+        let mut antinodes = IndexSet::new();
+        for (_, positions) in &input.antennas {
+            for &b in positions {
+                for &c in positions {
+                    if b == c {
+                        continue;
+                    }
+                    for antinode in [b + (b - c), c + (c - b)] {
+                        if input.grid.contains(antinode) {
+                            antinodes.insert(antinode);
+                        }
+                    }
+                }
+            }
+        }
+        antinodes.len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer {
+        let mut antinodes = IndexSet::new();
+        for (_, positions) in &input.antennas {
+            for &b in positions {
+                for &c in positions {
+                    if b == c {
+                        continue;
+                    }
+                    // Every cell in line with both antennas, on either side.
+                    for (start, step) in [(b, b - c), (c, c - b)] {
+                        let line = std::iter::successors(Some(start), |&p| Some(p + step));
+                        antinodes.extend(line.take_while(|&p| input.grid.contains(p)));
+                    }
+                }
+            }
+        }
+        antinodes.len()
+    }
+}
+
+#[test]
+fn example_round_trip() -> anyhow::Result<()> {
+    let text = include_str!("../../examples/example.txt");
+    let input = Day8::parse(text.as_bytes())?;
+    assert_eq!(text, input.to_string());
+    Ok(())
+}
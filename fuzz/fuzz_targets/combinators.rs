@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc2024_common::parse::{parse_lit, parse_mul, parse_num};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    parse_lit(data, b"mul(");
+    parse_num(data);
+    parse_mul(data);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day1_rust::Day1;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day1::parse(data) {
+        Day1::part1(&input);
+        Day1::part2(&input);
+    }
+});
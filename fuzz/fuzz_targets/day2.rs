@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day2_rust::Day2;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day2::parse(data) {
+        Day2::part1(&input);
+        Day2::part2(&input);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day3_rust::Day3;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day3::parse(data) {
+        Day3::part1(&input);
+        Day3::part2(&input);
+    }
+});
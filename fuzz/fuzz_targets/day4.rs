@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day4_rust::Day4;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day4::parse(data) {
+        Day4::part1(&input);
+        Day4::part2(&input);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day5_rust::Day5;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day5::parse(data) {
+        Day5::part1(&input);
+        Day5::part2(&input);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use aoc2024_common::solution::Solution;
+use day7_rust::Day7;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the parser accepts, the solver must handle without panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Day7::parse(data) {
+        // The search is exponential in the number of values, and puzzle equations have at
+        // most a dozen, so longer ones would only find timeouts.
+        if input.iter().all(|(_, values)| values.len() <= 12) {
+            Day7::part1(&input);
+            Day7::part2(&input);
+        }
+    }
+});